@@ -1,10 +1,18 @@
-use super::{Authorization, AuthorizationKind, Osu, OsuRef, Token};
+use super::{
+    etag::{EtagCache, ETAG_CACHE_CAPACITY},
+    Authorization, AuthorizationKind, Osu, OsuRef, OsuToken, RequestHook, Scope, Token, TokenHook,
+    DEFAULT_BASE_URL,
+};
 use crate::{error::OsuError, OsuResult};
 
-use hyper::client::Builder;
-use hyper_rustls::HttpsConnectorBuilder;
+use hyper::client::{Builder, Client as HyperClient, HttpConnector};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use leaky_bucket_lite::LeakyBucket;
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use time::OffsetDateTime;
 use tokio::sync::{oneshot, RwLock};
 
 #[cfg(feature = "cache")]
@@ -20,11 +28,20 @@ use crate::metrics::Metrics;
 /// For more info, check out <https://osu.ppy.sh/docs/index.html#client-credentials-grant>
 pub struct OsuBuilder {
     auth_kind: Option<AuthorizationKind>,
+    scopes: Option<Vec<Scope>>,
     client_id: Option<u64>,
     client_secret: Option<String>,
+    base_url: Option<String>,
     retries: usize,
+    ratelimit_retries: usize,
+    server_error_retries: usize,
+    capture_parsing_body: bool,
+    cache_etags: bool,
+    http: Option<HyperClient<HttpsConnector<HttpConnector>>>,
     timeout: Duration,
     per_second: u32,
+    on_request: Option<RequestHook>,
+    on_token_refresh: Option<TokenHook>,
 }
 
 impl Default for OsuBuilder {
@@ -32,11 +49,20 @@ impl Default for OsuBuilder {
     fn default() -> Self {
         Self {
             auth_kind: None,
+            scopes: None,
             client_id: None,
             client_secret: None,
+            base_url: None,
             retries: 2,
+            ratelimit_retries: 0,
+            server_error_retries: 0,
+            capture_parsing_body: false,
+            cache_etags: false,
+            http: None,
             timeout: Duration::from_secs(10),
             per_second: 15,
+            on_request: None,
+            on_token_refresh: None,
         }
     }
 }
@@ -64,14 +90,16 @@ impl OsuBuilder {
         let client_id = self.client_id.ok_or(OsuError::BuilderMissingId)?;
         let client_secret = self.client_secret.ok_or(OsuError::BuilderMissingSecret)?;
 
-        let connector = HttpsConnectorBuilder::new()
-            .with_native_roots()
-            .https_or_http()
-            .enable_http1()
-            .enable_http2()
-            .build();
+        let http = self.http.unwrap_or_else(|| {
+            let connector = HttpsConnectorBuilder::new()
+                .with_native_roots()
+                .https_or_http()
+                .enable_http1()
+                .enable_http2()
+                .build();
 
-        let http = Builder::default().build(connector);
+            Builder::default().build(connector)
+        });
 
         let ratelimiter = LeakyBucket::builder()
             .max(self.per_second)
@@ -82,15 +110,31 @@ impl OsuBuilder {
 
         let (tx, dropped_rx) = oneshot::channel();
 
+        let auth_kind = self.auth_kind.unwrap_or(AuthorizationKind::Client);
+
+        let scopes = self.scopes.unwrap_or_else(|| auth_kind.default_scopes());
+
+        let base_url = self.base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+
         let inner = Arc::new(OsuRef {
             client_id,
             client_secret,
+            base_url,
             http,
             ratelimiter,
             timeout: self.timeout,
-            auth_kind: self.auth_kind.unwrap_or_default(),
+            auth_kind,
+            scopes,
             token: RwLock::new(Token::default()),
             retries: self.retries,
+            ratelimit_retries: self.ratelimit_retries,
+            server_error_retries: self.server_error_retries,
+            capture_parsing_body: self.capture_parsing_body,
+            etags: self
+                .cache_etags
+                .then(|| Mutex::new(EtagCache::new(ETAG_CACHE_CAPACITY))),
+            on_request: self.on_request,
+            on_token_refresh: self.on_token_refresh,
         });
 
         // Acquire the initial API token
@@ -101,7 +145,7 @@ impl OsuBuilder {
             .map_err(|source| OsuError::UpdateToken { source })?;
 
         let expires_in = token.expires_in;
-        inner.token.write().await.update(token);
+        inner.update_token(&mut *inner.token.write().await, token);
 
         // Let an async worker update the token regularly
         Token::update_worker(Arc::clone(&inner), expires_in, dropped_rx);
@@ -157,6 +201,26 @@ impl OsuBuilder {
         self
     }
 
+    /// Specify the OAuth scopes that should be requested for the token.
+    ///
+    /// Defaults to `identify` and `public` if the client is authorized by a user
+    /// through [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization),
+    /// and to only `public` otherwise.
+    ///
+    /// Requests for endpoints that require a scope which was not specified
+    /// fail with [`OsuError::MissingScope`](crate::error::OsuError::MissingScope)
+    /// without being sent:
+    ///   - [`Osu::own_data`](crate::Osu::own_data) requires [`Scope::Identify`]
+    ///   - [`Osu::friends`](crate::Osu::friends) requires [`Scope::FriendsRead`]
+    ///
+    /// For more info, check out <https://osu.ppy.sh/docs/index.html#scopes>
+    #[inline]
+    pub fn scopes(mut self, scopes: &[Scope]) -> Self {
+        self.scopes = Some(scopes.to_vec());
+
+        self
+    }
+
     /// In case the request times out, retry up to this many times, defaults to 2.
     #[inline]
     pub fn retries(mut self, retries: usize) -> Self {
@@ -165,7 +229,100 @@ impl OsuBuilder {
         self
     }
 
+    /// In case the API responds with `429 Too Many Requests`, wait for the
+    /// duration specified in the response's `Retry-After` header and retry
+    /// up to this many times, defaults to 0 i.e. no retries.
+    ///
+    /// The wait time is capped at 60 seconds.
+    #[inline]
+    pub fn ratelimit_retries(mut self, retries: usize) -> Self {
+        self.ratelimit_retries = retries;
+
+        self
+    }
+
+    /// In case the API responds with a `5xx` status code to a `GET` request,
+    /// retry up to this many times, defaults to 0 i.e. no retries.
+    ///
+    /// Retries are delayed through exponential backoff with jitter,
+    /// starting at around 500ms. Once the total wait time would exceed
+    /// 30 seconds, the response is returned as is.
+    #[inline]
+    pub fn retry_on_server_error(mut self, max_retries: usize) -> Self {
+        self.server_error_retries = max_retries;
+
+        self
+    }
+
+    /// Whether the response body should be included in
+    /// [`OsuError::Parsing`](crate::error::OsuError::Parsing) if it failed to deserialize,
+    /// defaults to false.
+    ///
+    /// The captured body is truncated to 1000 bytes.
+    /// Note that it may contain sensitive data e.g. of the authenticated user.
+    #[inline]
+    pub fn capture_parsing_body(mut self, capture: bool) -> Self {
+        self.capture_parsing_body = capture;
+
+        self
+    }
+
+    /// Whether responses of beatmap and beatmapset lookups should be cached
+    /// through their `ETag`, defaults to false.
+    ///
+    /// Leaderboards, beatmapset searches, and beatmapset events are not cached.
+    ///
+    /// If enabled, requests for a previously cached URL are sent with the
+    /// `If-None-Match` header and the cached body is used if the API
+    /// responds with `304 Not Modified`.
+    ///
+    /// Only the 1000 most recently used responses are kept;
+    /// use [`Osu::clear_cache`](crate::Osu::clear_cache) to reset the cache.
+    #[inline]
+    pub fn cache_etags(mut self, cache: bool) -> Self {
+        self.cache_etags = cache;
+
+        self
+    }
+
+    /// Send requests to the given host instead of `https://osu.ppy.sh`,
+    /// e.g. a mock server for testing.
+    ///
+    /// Routes are appended to the base URL, i.e. tokens are requested from
+    /// `{base_url}/oauth/token` and endpoints from `{base_url}/api/v2/{route}`.
+    #[inline]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+
+        self.base_url.replace(base_url);
+
+        self
+    }
+
+    /// Use the given [`hyper`] client to send requests instead of building a new one.
+    ///
+    /// This allows for sharing the connection pool with other parts of an application
+    /// or configuring e.g. custom TLS roots through the connector.
+    /// Authorization and other headers are still added to each request.
+    #[inline]
+    pub fn http_client(mut self, client: HyperClient<HttpsConnector<HttpConnector>>) -> Self {
+        self.http.replace(client);
+
+        self
+    }
+
     /// Set the timeout for requests, defaults to 10 seconds.
+    ///
+    /// The timeout applies to each attempt, see [`OsuBuilder::retries`].
+    /// If the last attempt times out too, the request fails with
+    /// [`OsuError::RequestTimeout`](crate::error::OsuError::RequestTimeout).
+    ///
+    /// Some requests, e.g. [`GetPerformanceRankings`](crate::request::GetPerformanceRankings),
+    /// can override the timeout individually.
     #[inline]
     pub fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
@@ -177,13 +334,44 @@ impl OsuBuilder {
     /// The given value will be clamped between 1 and 20.
     ///
     /// Check out the osu!api's [terms of use] for acceptable values.
+    /// Note that exceeding the official limit may get your application's access revoked.
     ///
     /// [terms of use]: https://osu.ppy.sh/docs/index.html#terms-of-use
-
     #[inline]
     pub fn ratelimit(mut self, reqs_per_sec: u32) -> Self {
         self.per_second = reqs_per_sec.clamp(1, 20);
 
         self
     }
+
+    /// Provide a function that will be called with the URL of each request
+    /// right before the request is sent, e.g. to count or log requests.
+    #[inline]
+    pub fn on_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_request = Some(Box::new(hook));
+
+        self
+    }
+
+    /// Provide a function that will be called with the new tokens and
+    /// their expiry time whenever the client acquired a new access token,
+    /// e.g. to persist the tokens so they can be reused after a restart.
+    ///
+    /// This includes the initial token while building the client, tokens that
+    /// the client acquires on its own once the previous one expires, and tokens
+    /// acquired through [`Osu::refresh_token`](crate::Osu::refresh_token).
+    ///
+    /// Requests wait while the function is running so it should return quickly.
+    #[inline]
+    pub fn on_token_refresh<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&OsuToken, OffsetDateTime) + Send + Sync + 'static,
+    {
+        self.on_token_refresh = Some(Box::new(hook));
+
+        self
+    }
 }
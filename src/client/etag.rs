@@ -0,0 +1,149 @@
+use bytes::Bytes;
+use hyper::header::HeaderValue;
+use std::collections::HashMap;
+
+/// Maximum amount of responses that are cached through their `ETag`
+pub(super) const ETAG_CACHE_CAPACITY: usize = 1000;
+
+/// Response bodies that were cached alongside their `ETag`, keyed by URL.
+///
+/// Once the capacity is reached, the least recently used response is evicted.
+pub(super) struct EtagCache {
+    capacity: usize,
+    entries: HashMap<String, CachedResponse>,
+    uses: u64,
+}
+
+struct CachedResponse {
+    etag: HeaderValue,
+    body: Bytes,
+    last_used: u64,
+}
+
+impl EtagCache {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            uses: 0,
+        }
+    }
+
+    pub(super) fn etag(&mut self, key: &str) -> Option<HeaderValue> {
+        self.get(key).map(|cached| cached.etag.clone())
+    }
+
+    pub(super) fn body(&mut self, key: &str) -> Option<Bytes> {
+        self.get(key).map(|cached| cached.body.clone())
+    }
+
+    pub(super) fn insert(&mut self, key: String, etag: HeaderValue, body: Bytes) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.to_owned());
+
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+
+        self.uses += 1;
+
+        let cached = CachedResponse {
+            etag,
+            body,
+            last_used: self.uses,
+        };
+
+        self.entries.insert(key, cached);
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn get(&mut self, key: &str) -> Option<&CachedResponse> {
+        let cached = self.entries.get_mut(key)?;
+        self.uses += 1;
+        cached.last_used = self.uses;
+
+        Some(cached)
+    }
+}
+
+/// Whether responses of the route may be cached through their `ETag`.
+///
+/// Only beatmap and beatmapset metadata is cached, i.e. neither
+/// leaderboards nor search results or events.
+pub(super) fn has_etag_route(path: &str) -> bool {
+    let mut segments = path.split('/');
+
+    let is_lookup_or_id = |segment: &str| {
+        segment == "lookup" || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()))
+    };
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("beatmaps"), None, None) => true,
+        (Some("beatmaps" | "beatmapsets"), Some(segment), None) => is_lookup_or_id(segment),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(cache: &mut EtagCache, key: &str) {
+        let etag = HeaderValue::from_str(key).unwrap();
+        cache.insert(key.to_owned(), etag, Bytes::from(key.to_owned()));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = EtagCache::new(2);
+
+        insert(&mut cache, "a");
+        insert(&mut cache, "b");
+        assert!(cache.etag("a").is_some());
+
+        insert(&mut cache, "c");
+
+        assert_eq!(cache.body("a").as_deref(), Some(b"a".as_ref()));
+        assert!(cache.body("b").is_none());
+        assert_eq!(cache.body("c").as_deref(), Some(b"c".as_ref()));
+    }
+
+    #[test]
+    fn replacing_does_not_evict() {
+        let mut cache = EtagCache::new(2);
+
+        insert(&mut cache, "a");
+        insert(&mut cache, "b");
+        insert(&mut cache, "b");
+
+        assert!(cache.body("a").is_some());
+        assert!(cache.body("b").is_some());
+    }
+
+    #[test]
+    fn etag_routes() {
+        assert!(has_etag_route("beatmaps"));
+        assert!(has_etag_route("beatmaps/lookup"));
+        assert!(has_etag_route("beatmaps/123"));
+        assert!(has_etag_route("beatmapsets/123"));
+        assert!(has_etag_route("beatmapsets/lookup"));
+
+        assert!(!has_etag_route("beatmaps/123/scores"));
+        assert!(!has_etag_route("beatmaps/123/scores/users/2"));
+        assert!(!has_etag_route("beatmapsets/search"));
+        assert!(!has_etag_route("beatmapsets/events"));
+        assert!(!has_etag_route("users/2/beatmapsets/ranked"));
+    }
+}
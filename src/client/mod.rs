@@ -1,32 +1,44 @@
 mod builder;
+mod etag;
 mod token;
 
 use bytes::Bytes;
+use etag::{has_etag_route, EtagCache};
 use token::{Authorization, AuthorizationKind, Token, TokenResponse};
 
 pub use builder::OsuBuilder;
-pub use token::Scope;
+pub use token::{OsuToken, Scope};
 
-use crate::{error::OsuError, model::GameMode, request::*, OsuResult};
+use crate::{
+    error::{ApiError, OsuError},
+    model::GameMode,
+    request::*,
+    OsuResult,
+};
 
 use hyper::{
     body::{Body as HyperBody, HttpBody, SizeHint},
     client::{Client as HyperClient, HttpConnector},
-    header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
+    header::{
+        HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+        RETRY_AFTER, USER_AGENT,
+    },
     HeaderMap, Method, Request as HyperRequest, Response, StatusCode,
 };
 use hyper_rustls::HttpsConnector;
 use leaky_bucket_lite::LeakyBucket;
 use serde::de::DeserializeOwned;
+use serde_json::Error as SerdeError;
 use std::{
     convert::Infallible,
     mem,
     ops::Drop,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex},
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
+use time::OffsetDateTime;
 use tokio::sync::{oneshot::Sender, RwLock};
 use url::Url;
 
@@ -64,6 +76,90 @@ impl Osu {
         OsuBuilder::default()
     }
 
+    /// The current access token and, if the client was authorized by a user, the refresh token.
+    ///
+    /// The client updates its tokens on its own so this can be used to persist them.
+    pub async fn token(&self) -> OsuToken {
+        self.inner.token.read().await.to_public()
+    }
+
+    /// When the current access token expires.
+    ///
+    /// `None` if the previous token expired and no new one has been acquired yet.
+    /// The client acquires a new token on its own shortly before this time.
+    pub async fn token_expires_at(&self) -> Option<OffsetDateTime> {
+        self.inner.token.read().await.expires_at
+    }
+
+    /// Acquire a new access token right away instead of waiting for the current one to expire,
+    /// e.g. to avoid a token rollover during a busy period.
+    ///
+    /// If the client was authorized by a user, the refresh token is used and replaced as well.
+    ///
+    /// Requests wait for the refresh to finish. The client's own refresh of the token,
+    /// as well as a refresh due to a `401` response, is synchronized with this one so that
+    /// a refresh token is never used twice. However, the client's own refresh is still
+    /// scheduled based on the previous token so it may acquire yet another token
+    /// earlier than necessary.
+    pub async fn refresh_token(&self) -> OsuResult<()> {
+        let mut token = self.inner.token.write().await;
+
+        let response = self
+            .inner
+            .request_token_with(token.refresh.as_deref())
+            .await
+            .map_err(Box::new)
+            .map_err(|source| OsuError::UpdateToken { source })?;
+
+        self.inner.update_token(&mut token, response);
+
+        Ok(())
+    }
+
+    /// Whether the client was authorized by a user through the OAuth
+    /// authorization code grant, see [`OsuBuilder::with_authorization`].
+    #[inline]
+    pub(crate) fn is_user_authorized(&self) -> bool {
+        matches!(self.inner.auth_kind, AuthorizationKind::User(_))
+    }
+
+    /// Checks whether the given scope was requested for the client's token.
+    #[inline]
+    pub(crate) fn check_scope(&self, scope: Scope) -> OsuResult<()> {
+        if self.inner.scopes.contains(&scope) {
+            Ok(())
+        } else {
+            Err(OsuError::MissingScope { scope })
+        }
+    }
+
+    /// Remove all responses that were cached through their `ETag`,
+    /// see [`OsuBuilder::cache_etags`].
+    ///
+    /// Note that this does not affect the username cache of the `cache` feature.
+    pub fn clear_cache(&self) {
+        if let Some(etags) = self.inner.etags.as_ref() {
+            if let Ok(mut etags) = etags.lock() {
+                etags.clear();
+            }
+        }
+    }
+
+    /// Check whether the API is reachable and accepts the client's token.
+    ///
+    /// If the client was authorized by a user and has the [`Scope::Identify`] scope,
+    /// this requests the user's own data through the `me` endpoint.
+    /// Otherwise, it requests the `spotlights` endpoint.
+    /// Both endpoints require a valid token.
+    /// Note that the request counts towards the ratelimit.
+    pub async fn ping(&self) -> OsuResult<()> {
+        if self.is_user_authorized() && self.check_scope(Scope::Identify).is_ok() {
+            self.own_data().await.map(|_| ())
+        } else {
+            self.spotlights().await.map(|_| ())
+        }
+    }
+
     /// Returns an [`IntCounterVec`](crate::prelude::IntCounterVec) from
     /// [prometheus](https://crates.io/crates/prometheus) containing
     /// a counter for each request type.
@@ -80,12 +176,29 @@ impl Osu {
     /// The contained [`Beatmapset`](crate::model::beatmap::Beatmapset) will
     /// have these options filled: `legacy_thread_url`, `ratings`,
     /// `ranked_date` (if not unranked) and `submitted_date` (if submitted).
+    ///
+    /// The map must be specified through either
+    /// [`map_id`](GetBeatmap::map_id), [`checksum`](GetBeatmap::checksum),
+    /// or [`filename`](GetBeatmap::filename).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # use rosu_v2::Osu;
+    /// # async fn example(osu: &Osu) -> rosu_v2::OsuResult<()> {
+    /// let map = osu.beatmap().map_id(1116573).await?;
+    /// let map = osu.beatmap().checksum("a708a5b90349e98b399f2a1c9fce5422").await?;
+    /// # Ok(()) }
+    /// ```
     #[inline]
     pub fn beatmap(&self) -> GetBeatmap<'_> {
         GetBeatmap::new(self)
     }
 
-    /// Get a vec of at most 50 [`BeatmapCompact`](crate::model::beatmap::BeatmapCompact)s.
+    /// Get a vec of [`BeatmapCompact`](crate::model::beatmap::BeatmapCompact)s.
+    ///
+    /// Any amount of ids can be given; they're requested in chunks of 50
+    /// and the maps are returned in the order of their ids.
     ///
     /// The contained maps will have these options filled: `mapset`,
     /// `fail_times`, and `max_combo` (if available for mode).
@@ -97,7 +210,8 @@ impl Osu {
         GetBeatmaps::new(self, map_ids)
     }
 
-    /// Get a vec of [`Score`](crate::model::score::Score).
+    /// Get the leaderboard of a beatmap in form of
+    /// [`BeatmapScores`](crate::model::score::BeatmapScores).
     ///
     /// The contained scores will have the following options filled:
     /// `pp` (if ranked or approved), and `user`.
@@ -115,7 +229,11 @@ impl Osu {
         GetBeatmapDifficultyAttributes::new(self, map_id)
     }
 
-    /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore).
+    /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore)
+    /// or `None` if the user has no score on the map.
+    ///
+    /// The API does not distinguish between a missing score and an unknown
+    /// map or user so those also resolve to `None`.
     ///
     /// The contained [`Score`](crate::model::score::Score) will have the
     /// `map` and `user` options filled.
@@ -136,7 +254,11 @@ impl Osu {
         GetBeatmapUserScores::new(self, map_id, user_id)
     }
 
-    /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore).
+    /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore)
+    /// or `None` if the user has no score on the map.
+    ///
+    /// The API does not distinguish between a missing score and an unknown
+    /// map or user so those also resolve to `None`.
     ///
     /// The contained [`Score`](crate::model::score::Score) will have the
     /// `map` and `user` options filled.
@@ -197,6 +319,9 @@ impl Osu {
 
     /// Get a [`BeatmapsetEvents`](crate::model::beatmap::BeatmapsetEvents)
     /// struct containing the most recent mapset events.
+    ///
+    /// The events can be filtered e.g. by user and type,
+    /// see [`GetBeatmapsetEvents`].
     #[inline]
     pub fn beatmapset_events(&self) -> GetBeatmapsetEvents<'_> {
         GetBeatmapsetEvents::new(self)
@@ -236,6 +361,35 @@ impl Osu {
         GetBeatmapsetSearch::new(self)
     }
 
+    /// Get a [`ChangelogListing`](crate::model::changelog::ChangelogListing)
+    /// of osu! builds, optionally filtered by update stream and version range.
+    ///
+    /// The contained builds will have their `changelog_entries` filled.
+    #[inline]
+    pub fn changelog(&self) -> GetChangelog<'_> {
+        GetChangelog::new(self)
+    }
+
+    /// Get a single [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)
+    /// through its update stream and version, e.g. `("lazer", "2022.101.0")`.
+    ///
+    /// The build will have its `changelog_entries` and `update_stream` filled.
+    #[inline]
+    pub fn changelog_build(
+        &self,
+        stream: impl Into<String>,
+        version: impl Into<String>,
+    ) -> GetChangelogBuild<'_> {
+        GetChangelogBuild::new(self, stream, version)
+    }
+
+    /// Get a single [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)
+    /// through its id, see [`Osu::changelog_build`].
+    #[inline]
+    pub fn changelog_build_by_id(&self, build_id: u32) -> GetChangelogBuild<'_> {
+        GetChangelogBuild::by_id(self, build_id)
+    }
+
     /// Get a list of comments and their replies up to two levels deep
     /// in form of a [`CommentBundle`](crate::model::comments::CommentBundle) .
     #[inline]
@@ -267,6 +421,13 @@ impl Osu {
         GetCountryRankings::new(self, mode)
     }
 
+    /// Get [`Events`](crate::model::recent_event::Events) of the site-wide
+    /// event feed, e.g. achieved medals, ranks on a beatmap, or beatmapset status updates.
+    #[inline]
+    pub fn events(&self) -> GetEvents<'_> {
+        GetEvents::new(self)
+    }
+
     /// Get a [`ForumPosts`](crate::model::forum::ForumPosts) struct for a forum topic
     #[inline]
     pub fn forum_posts(&self, topic_id: u64) -> GetForumPosts<'_> {
@@ -289,12 +450,67 @@ impl Osu {
         GetUserKudosu::new(self, user_id.into())
     }
 
+    /// Get a vec of [`UserCompact`](crate::model::user::UserCompact) sorted
+    /// by their total kudosu, i.e. the current kudosu leaderboard.
+    ///
+    /// The `kudosu` option of the users will be filled.
+    #[inline]
+    pub fn kudosu_rankings(&self) -> GetKudosuRankings<'_> {
+        GetKudosuRankings::new(self)
+    }
+
+    /// Get a [`MultiplayerScore`](crate::model::multiplayer::MultiplayerScore)
+    /// on a playlist item in a multiplayer room.
+    #[inline]
+    pub fn multiplayer_score(
+        &self,
+        room_id: u32,
+        playlist_id: u32,
+        score_id: u64,
+    ) -> GetMultiplayerScore<'_> {
+        GetMultiplayerScore::new(self, room_id, playlist_id, score_id)
+    }
+
+    /// Get [`MultiplayerScores`](crate::model::multiplayer::MultiplayerScores)
+    /// of a playlist item in a multiplayer room.
+    ///
+    /// The contained scores will have the `user` option filled.
+    #[inline]
+    pub fn multiplayer_scores(&self, room_id: u32, playlist_id: u32) -> GetMultiplayerScores<'_> {
+        GetMultiplayerScores::new(self, room_id, playlist_id)
+    }
+
+    /// Get a multiplayer [`Room`](crate::model::multiplayer::Room).
+    ///
+    /// The playlist items of the room contain the map and mods that
+    /// [`MultiplayerScores`](crate::model::multiplayer::MultiplayerScores) refer to.
+    #[inline]
+    pub fn room(&self, room_id: u32) -> GetRoom<'_> {
+        GetRoom::new(self, room_id)
+    }
+
+    /// Get a vec of multiplayer [`Room`](crate::model::multiplayer::Room)s.
+    ///
+    /// The playlist of each room only contains its current item.
+    #[inline]
+    pub fn rooms(&self) -> GetRooms<'_> {
+        GetRooms::new(self)
+    }
+
     /// Get [`News`](crate::model::news::News).
     #[inline]
     pub fn news(&self) -> GetNews<'_> {
         GetNews::new(self)
     }
 
+    /// Get a single [`NewsPost`](crate::model::news::NewsPost) through its slug.
+    ///
+    /// The `content` option of the post will be filled.
+    #[inline]
+    pub fn news_post(&self, slug: impl Into<String>) -> GetNewsPost<'_> {
+        GetNewsPost::new(self, slug)
+    }
+
     /// Get an [`OsuMatch`](crate::model::matches::OsuMatch).
     #[inline]
     pub fn osu_match(&self, match_id: u32) -> GetMatch<'_> {
@@ -308,12 +524,29 @@ impl Osu {
         GetMatches::new(self)
     }
 
+    /// Get the friends of the authenticated user as a vec of
+    /// [`UserCompact`](crate::model::user::UserCompact).
+    ///
+    /// The contained users will have the `cover`, `groups`, `statistics`,
+    /// and `support_level` options filled.
+    ///
+    /// Note that the client has to be initialized with the `friends.read` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization)
+    /// and [`OsuBuilder::scopes`](crate::OsuBuilder::scopes).
+    #[inline]
+    pub fn friends(&self) -> GetFriends<'_> {
+        GetFriends::new(self)
+    }
+
     /// Get the [`User`](crate::model::user::User) of the authenticated user.
     ///
     /// Note that the client has to be initialized with the `identify` scope
     /// through the OAuth process in order for this endpoint to not return an error.
     ///
-    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization)
+    /// and [`OsuBuilder::scopes`](crate::OsuBuilder::scopes).
     #[inline]
     pub fn own_data(&self) -> GetOwnData<'_> {
         GetOwnData::new(self)
@@ -373,9 +606,12 @@ impl Osu {
     /// (will contain `artist_unicode` and `title_unicode`), `pp`
     /// (if ranked), `rank_global` (if on leaderboard map) and `user`
     /// (will contain `last_visited`, `country`, `cover` and `groups`)
+    ///
+    /// Unless a mode is specified through [`GetScore::mode`], the score id is
+    /// considered to be mode-agnostic.
     #[inline]
-    pub fn score(&self, score_id: u64, mode: GameMode) -> GetScore<'_> {
-        GetScore::new(self, score_id, mode)
+    pub fn score(&self, score_id: u64) -> GetScore<'_> {
+        GetScore::new(self, score_id)
     }
 
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
@@ -393,6 +629,10 @@ impl Osu {
     }
 
     /// Get the vec of [`Spotlight`](crate::model::ranking::Spotlight).
+    ///
+    /// Use the `spotlight_id` of a spotlight in
+    /// [`GetChartRankings::spotlight`](crate::request::GetChartRankings::spotlight)
+    /// to request its charts.
     #[inline]
     pub fn spotlights(&self) -> GetSpotlights<'_> {
         GetSpotlights::new(self)
@@ -413,6 +653,16 @@ impl Osu {
         GetUser::new(self, user_id)
     }
 
+    /// Get the [`UserCompact`](crate::model::user::UserCompact) at the given
+    /// rank of the pp leaderboard of a mode.
+    ///
+    /// Ranks start at 1 and only the top 10,000 are available.
+    /// Only the single page of [`Osu::performance_rankings`] containing the rank is requested.
+    #[inline]
+    pub fn user_at_rank(&self, mode: GameMode, rank: u32) -> GetUserAtRank<'_> {
+        GetUserAtRank::new(self, mode, rank)
+    }
+
     /// Get the [`Beatmapset`](crate::model::beatmap::Beatmapset)s of a user by their id.
     ///
     /// If no map type specified, either manually through
@@ -493,10 +743,10 @@ impl Osu {
     ///
     /// - The API provides at most 100 results per requests and defaults to 5.
     /// - For the `recent` score type, failed score are excluded by default.
-    /// Use [`include_fails`](crate::request::GetUserScores::include_fails)
-    /// to include them.
+    ///   Use [`include_fails`](crate::request::GetUserScores::include_fails)
+    ///   to include them.
     /// - For the `firsts` score type, `pp` will only be `Some` if the map
-    /// is not loved.
+    ///   is not loved.
     #[cfg(not(feature = "cache"))]
     #[inline]
     pub fn user_scores(&self, user_id: u32) -> GetUserScores<'_> {
@@ -524,23 +774,34 @@ impl Osu {
     ///
     /// - The API provides at most 100 results per requests and defaults to 5.
     /// - For the `recent` score type, failed score are excluded by default.
-    /// Use [`include_fails`](crate::request::GetUserScores::include_fails)
-    /// to include them.
+    ///   Use [`include_fails`](crate::request::GetUserScores::include_fails)
+    ///   to include them.
     /// - For the `firsts` score type, `pp` will only be `Some` if the map
-    /// is not loved.
+    ///   is not loved.
     #[cfg(feature = "cache")]
     #[inline]
     pub fn user_scores(&self, user_id: impl Into<UserId>) -> GetUserScores<'_> {
         GetUserScores::new(self, user_id.into())
     }
 
-    /// Get a vec of [`UserCompact`](crate::model::user::UserCompact).
-    #[deprecated = "The API currently doesn't allow this endpoint for public use"]
+    /// Get a vec of up to 50 [`UserCompact`](crate::model::user::UserCompact)
+    /// ordered the same way as the given ids.
+    ///
+    /// Ids of users that the API does not provide are skipped.
     #[inline]
     pub fn users(&self, user_ids: &[u32]) -> GetUsers<'_> {
         GetUsers::new(self, user_ids)
     }
 
+    /// Get a `HashMap` of [`UserCompact`](crate::model::user::UserCompact)s keyed by their user id.
+    ///
+    /// Unlike [`Osu::users`], any amount of ids can be given; they're requested in chunks of 50.
+    /// Ids of users that the API does not provide are missing.
+    #[inline]
+    pub fn users_map(&self, user_ids: &[u32]) -> GetUsersMap<'_> {
+        GetUsersMap::new(self, user_ids)
+    }
+
     /// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data.
     ///
     /// `locale` adjusts the language, e.g. `en` for english, `de` for german, ...
@@ -604,14 +865,26 @@ impl Drop for Osu {
 pub(crate) struct OsuRef {
     client_id: u64,
     client_secret: String,
-    http: HyperClient<HttpsConnector<HttpConnector>, BodyBytes>,
+    base_url: String,
+    http: HyperClient<HttpsConnector<HttpConnector>>,
     timeout: Duration,
     ratelimiter: LeakyBucket,
     auth_kind: AuthorizationKind,
+    scopes: Vec<Scope>,
     token: RwLock<Token>,
     retries: usize,
+    ratelimit_retries: usize,
+    server_error_retries: usize,
+    capture_parsing_body: bool,
+    etags: Option<Mutex<EtagCache>>,
+    on_request: Option<RequestHook>,
+    on_token_refresh: Option<TokenHook>,
 }
 
+pub(crate) type RequestHook = Box<dyn Fn(&str) + Send + Sync>;
+
+pub(crate) type TokenHook = Box<dyn Fn(&OsuToken, OffsetDateTime) + Send + Sync>;
+
 static MY_USER_AGENT: &str = concat!(
     "Rust API v2 (",
     env!("CARGO_PKG_REPOSITORY"),
@@ -625,18 +898,38 @@ const X_API_VERSION: &str = "x-api-version";
 
 const API_VERSION: u32 = 20220705;
 
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Maximum length of a response body captured in [`OsuError::Parsing`]
+const MAX_PARSING_BODY_LEN: usize = 1000;
+
+const TOKEN_ROUTE: &str = "oauth/token";
+
+pub(crate) const DEFAULT_BASE_URL: &str = "https://osu.ppy.sh";
+
+/// Total time that may be spent waiting between retries on server errors
+const MAX_SERVER_ERROR_WAIT: Duration = Duration::from_secs(30);
+
 impl OsuRef {
     async fn request_token(&self) -> OsuResult<TokenResponse> {
+        let refresh = self.token.read().await.refresh.clone();
+
+        self.request_token_with(refresh.as_deref()).await
+    }
+
+    async fn request_token_with(&self, refresh: Option<&str>) -> OsuResult<TokenResponse> {
         let mut body = Body::default();
         body.push_without_quotes("client_id", self.client_id);
         body.push_with_quotes("client_secret", &self.client_secret);
 
+        let scopes = token::scopes_to_string(&self.scopes);
+
         match &self.auth_kind {
-            AuthorizationKind::Client(scope) => {
+            AuthorizationKind::Client => {
                 body.push_with_quotes("grant_type", "client_credentials");
-                body.push_with_quotes("scope", scope);
+                body.push_with_quotes("scope", scopes);
             }
-            AuthorizationKind::User(auth) => match &self.token.read().await.refresh {
+            AuthorizationKind::User(auth) => match refresh {
                 Some(refresh) => {
                     body.push_with_quotes("grant_type", "refresh_token");
                     body.push_with_quotes("refresh_token", refresh);
@@ -645,14 +938,13 @@ impl OsuRef {
                     body.push_with_quotes("grant_type", "authorization_code");
                     body.push_with_quotes("redirect_uri", &auth.redirect_uri);
                     body.push_with_quotes("code", &auth.code);
-                    // FIXME: let users decide which scopes to use?
-                    body.push_with_quotes("scope", "identify public");
+                    body.push_with_quotes("scope", scopes);
                 }
             },
         };
 
         let bytes = BodyBytes::from(body);
-        let url = "https://osu.ppy.sh/oauth/token";
+        let url = format!("{}/oauth/token", self.base_url);
 
         let req = HyperRequest::builder()
             .method(Method::POST)
@@ -663,26 +955,71 @@ impl OsuRef {
             .header(CONTENT_LENGTH, bytes.len())
             .body(bytes)?;
 
-        let resp = self.send_request(req).await?;
-        let bytes = self.handle_status(resp).await?;
+        let resp = self.send_request(req, self.timeout).await?;
+        let bytes = self.handle_status(resp, TOKEN_ROUTE).await?;
 
-        parse_bytes(bytes)
+        self.parse_bytes(bytes, TOKEN_ROUTE)
+    }
+
+    /// Store the acquired token and pass it to the `on_token_refresh` hook, if any.
+    fn update_token(&self, token: &mut Token, response: TokenResponse) {
+        token.update(response);
+
+        if let (Some(hook), Some(expires_at)) = (self.on_token_refresh.as_ref(), token.expires_at) {
+            hook(&token.to_public(), expires_at);
+        }
     }
 
     async fn request<T: DeserializeOwned>(&self, req: Request) -> OsuResult<T> {
+        let route = req.path.clone();
         let bytes = self.request_raw(req).await?;
 
         // let text = String::from_utf8_lossy(&bytes);
         // println!("Response:\n{}", text);
 
-        parse_bytes(bytes)
+        self.parse_bytes(bytes, &route)
     }
 
     async fn request_raw(&self, req: Request) -> OsuResult<Bytes> {
-        let resp = self.raw(req).await?;
-        let bytes = self.handle_status(resp).await?;
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("request", method = %req.method, route = %req.path);
+
+        let fut = async move {
+            let route = req.path.clone();
+
+            #[cfg(feature = "tracing")]
+            let start = std::time::Instant::now();
 
-        Ok(bytes)
+            let resp = self.raw(req).await?;
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                status = resp.status().as_u16(),
+                elapsed = ?start.elapsed(),
+                "Received response"
+            );
+
+            self.handle_status(resp, &route).await
+        };
+
+        #[cfg(feature = "tracing")]
+        let fut = tracing::Instrument::instrument(fut, span);
+
+        fut.await
+    }
+
+    fn parse_bytes<T: DeserializeOwned>(&self, bytes: Bytes, route: &str) -> OsuResult<T> {
+        serde_json::from_slice(&bytes).map_err(|source| self.parsing_error(&bytes, route, source))
+    }
+
+    fn parsing_error(&self, bytes: &[u8], route: &str, source: SerdeError) -> OsuError {
+        let body = self.capture_parsing_body.then(|| truncated_body(bytes));
+
+        OsuError::Parsing {
+            body,
+            route: route.to_owned(),
+            source,
+        }
     }
 
     async fn raw(&self, req: Request) -> OsuResult<Response<HyperBody>> {
@@ -691,48 +1028,248 @@ impl OsuRef {
             method,
             path,
             body,
+            timeout,
         } = req;
 
-        let url = format!("https://osu.ppy.sh/api/v2/{}{}", path, query);
+        let timeout = timeout.unwrap_or(self.timeout);
+
+        let url = format!("{}/api/v2/{}{}", self.base_url, path, query);
         let url = Url::parse(&url).map_err(|source| OsuError::Url { source, url })?;
         debug!("URL: {}", url);
 
-        if let Some(ref token) = self.token.read().await.access {
-            let value = HeaderValue::from_str(token)
-                .map_err(|source| OsuError::CreatingTokenHeader { source })?;
+        if let Some(ref on_request) = self.on_request {
+            on_request(url.as_str());
+        }
+
+        let bytes = BodyBytes::from(body);
+
+        let etag_key = (self.etags.is_some() && method == Method::GET && has_etag_route(&path))
+            .then(|| url.as_str().to_owned());
+
+        let if_none_match = etag_key.as_deref().and_then(|key| self.cached_etag(key));
+
+        let resp = self
+            .send_authorized(&method, &url, &bytes, if_none_match.as_ref(), timeout)
+            .await?;
+        let resp = self.handle_etag(resp, etag_key.as_deref()).await?;
+
+        if resp.status() != StatusCode::NOT_MODIFIED || if_none_match.is_none() {
+            return Ok(resp);
+        }
+
+        // The cached body was removed while the request was in flight
+        // so request the full body again
+        debug!("Got a 304 response without cached body, retry without ETag...");
+
+        let resp = self
+            .send_authorized(&method, &url, &bytes, None, timeout)
+            .await?;
+
+        self.handle_etag(resp, etag_key.as_deref()).await
+    }
+
+    /// Send the request with the current access token.
+    ///
+    /// If the API responds with `401 Unauthorized` and the token can be refreshed,
+    /// the token is refreshed and the request is sent once more.
+    async fn send_authorized(
+        &self,
+        method: &Method,
+        url: &Url,
+        bytes: &BodyBytes,
+        if_none_match: Option<&HeaderValue>,
+        timeout: Duration,
+    ) -> OsuResult<Response<HyperBody>> {
+        let req = self
+            .build_request(method, url, bytes, if_none_match)
+            .await?;
+        let sent_token = req.headers().get(AUTHORIZATION).cloned();
+        let resp = self.send_request(req, timeout).await?;
+
+        if resp.status() != StatusCode::UNAUTHORIZED || !self.can_refresh_token().await {
+            return Ok(resp);
+        }
 
-            let bytes = BodyBytes::from(body);
+        // The user's access token may have been revoked or expired early
+        // so refresh it and try again once
+        debug!("Got a 401 response, refreshing the token...");
 
-            let mut req_builder = HyperRequest::builder()
-                .method(method)
-                .uri(url.as_str())
-                .header(AUTHORIZATION, value)
-                .header(USER_AGENT, MY_USER_AGENT)
-                .header(X_API_VERSION, API_VERSION)
-                .header(ACCEPT, APPLICATION_JSON)
-                .header(CONTENT_LENGTH, bytes.len());
+        self.refresh_token(sent_token.as_ref())
+            .await
+            .map_err(Box::new)
+            .map_err(|source| OsuError::UpdateToken { source })?;
+
+        let req = self
+            .build_request(method, url, bytes, if_none_match)
+            .await?;
 
-            if !bytes.is_empty() {
-                req_builder = req_builder.header(CONTENT_TYPE, APPLICATION_JSON);
+        self.send_request(req, timeout).await
+    }
+
+    /// Refresh the token unless it already changed since it was sent as `sent_token`.
+    ///
+    /// The write lock is held throughout so that concurrent requests, as well as
+    /// the token update worker, do not use the same single-use refresh token.
+    async fn refresh_token(&self, sent_token: Option<&HeaderValue>) -> OsuResult<()> {
+        let mut token = self.token.write().await;
+
+        let current = token.access.as_deref().map(str::as_bytes);
+
+        if current != sent_token.map(HeaderValue::as_bytes) {
+            debug!("Token was already refreshed by another request");
+
+            return Ok(());
+        }
+
+        let response = self.request_token_with(token.refresh.as_deref()).await?;
+        self.update_token(&mut token, response);
+
+        Ok(())
+    }
+
+    fn cached_etag(&self, key: &str) -> Option<HeaderValue> {
+        self.etags.as_ref()?.lock().ok()?.etag(key)
+    }
+
+    /// Store the body of responses with an `ETag` header and
+    /// replace the body of `304 Not Modified` responses with the cached one.
+    ///
+    /// If there is no cached body, the `304` response is returned as is.
+    async fn handle_etag(
+        &self,
+        resp: Response<HyperBody>,
+        etag_key: Option<&str>,
+    ) -> OsuResult<Response<HyperBody>> {
+        let (key, etags) = match (etag_key, self.etags.as_ref()) {
+            (Some(key), Some(etags)) => (key, etags),
+            _ => return Ok(resp),
+        };
+
+        match resp.status() {
+            StatusCode::NOT_MODIFIED => {
+                let cached = etags.lock().ok().and_then(|mut etags| etags.body(key));
+
+                match cached {
+                    Some(body) => {
+                        debug!("Got a 304 response, using cached body");
+
+                        Ok(Response::new(HyperBody::from(body)))
+                    }
+                    None => Ok(resp),
+                }
             }
+            StatusCode::OK => {
+                let etag = match resp.headers().get(ETAG) {
+                    Some(etag) => etag.to_owned(),
+                    None => return Ok(resp),
+                };
 
-            let req = req_builder.body(bytes)?;
+                let (parts, body) = resp.into_parts();
 
-            self.send_request(req).await
-        } else {
-            Err(OsuError::NoToken)
+                let body = hyper::body::to_bytes(body)
+                    .await
+                    .map_err(|source| OsuError::ChunkingResponse { source })?;
+
+                if let Ok(mut etags) = etags.lock() {
+                    etags.insert(key.to_owned(), etag, body.clone());
+                }
+
+                Ok(Response::from_parts(parts, HyperBody::from(body)))
+            }
+            _ => Ok(resp),
         }
     }
 
-    async fn send_request(&self, req: HyperRequest<BodyBytes>) -> OsuResult<Response<HyperBody>> {
+    async fn build_request(
+        &self,
+        method: &Method,
+        url: &Url,
+        bytes: &BodyBytes,
+        if_none_match: Option<&HeaderValue>,
+    ) -> OsuResult<HyperRequest<BodyBytes>> {
+        let token = self.token.read().await;
+        let token = token.access.as_deref().ok_or(OsuError::NoToken)?;
+
+        let value = HeaderValue::from_str(token)
+            .map_err(|source| OsuError::CreatingTokenHeader { source })?;
+
+        let mut req_builder = HyperRequest::builder()
+            .method(method)
+            .uri(url.as_str())
+            .header(AUTHORIZATION, value)
+            .header(USER_AGENT, MY_USER_AGENT)
+            .header(X_API_VERSION, API_VERSION)
+            .header(ACCEPT, APPLICATION_JSON)
+            .header(CONTENT_LENGTH, bytes.len());
+
+        if !bytes.is_empty() {
+            req_builder = req_builder.header(CONTENT_TYPE, APPLICATION_JSON);
+        }
+
+        if let Some(etag) = if_none_match {
+            req_builder = req_builder.header(IF_NONE_MATCH, etag);
+        }
+
+        Ok(req_builder.body(bytes.to_owned())?)
+    }
+
+    async fn can_refresh_token(&self) -> bool {
+        matches!(self.auth_kind, AuthorizationKind::User(_))
+            && self.token.read().await.refresh.is_some()
+    }
+
+    async fn send_request(
+        &self,
+        req: HyperRequest<BodyBytes>,
+        timeout: Duration,
+    ) -> OsuResult<Response<HyperBody>> {
         self.ratelimiter.acquire_one().await;
 
         let mut attempt = 0;
+        let mut ratelimit_attempt = 0;
+        let mut server_error_attempt = 0;
+        let mut server_error_wait = Duration::ZERO;
 
-        loop {
-            let req = clone_req(&req);
+        // Only GET requests are idempotent and can safely be retried
+        let server_error_retries = if req.method() == Method::GET {
+            self.server_error_retries
+        } else {
+            0
+        };
 
-            match tokio::time::timeout(self.timeout, self.http.request(req)).await {
+        loop {
+            let req = clone_req(&req).map(|bytes| HyperBody::from(bytes.0));
+
+            match tokio::time::timeout(timeout, self.http.request(req)).await {
+                Ok(Ok(resp))
+                    if resp.status() == StatusCode::TOO_MANY_REQUESTS
+                        && ratelimit_attempt < self.ratelimit_retries =>
+                {
+                    let delay = retry_after(resp.headers());
+                    warn!(
+                        "Got a 429 response on attempt {ratelimit_attempt}, retry in {delay:?}..."
+                    );
+                    ratelimit_attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(Ok(resp))
+                    if resp.status().is_server_error()
+                        && server_error_attempt < server_error_retries =>
+                {
+                    let delay = server_error_backoff(server_error_attempt);
+
+                    if server_error_wait + delay > MAX_SERVER_ERROR_WAIT {
+                        return Ok(resp);
+                    }
+
+                    warn!(
+                        "Got a {} response on attempt {server_error_attempt}, retry in {delay:?}...",
+                        resp.status().as_u16()
+                    );
+                    server_error_attempt += 1;
+                    server_error_wait += delay;
+                    tokio::time::sleep(delay).await;
+                }
                 Ok(res) => return res.map_err(|source| OsuError::Request { source }),
                 Err(_) if attempt < self.retries => {
                     warn!("Timed out on attempt {attempt}, retry...");
@@ -743,7 +1280,7 @@ impl OsuRef {
         }
     }
 
-    async fn handle_status(&self, resp: Response<HyperBody>) -> OsuResult<Bytes> {
+    async fn handle_status(&self, resp: Response<HyperBody>, route: &str) -> OsuResult<Bytes> {
         let status = resp.status();
 
         let bytes = hyper::body::to_bytes(resp.into_body())
@@ -762,28 +1299,59 @@ impl OsuRef {
             _ => {}
         }
 
+        // Not every error response comes with a JSON body e.g. a 502 from a proxy
+        let source = serde_json::from_slice(&bytes).unwrap_or(ApiError { error: None });
         let body = String::from_utf8_lossy(&bytes).into_owned();
 
-        let source = match serde_json::from_slice(&bytes) {
-            Ok(source) => source,
-            Err(source) => return Err(OsuError::Parsing { body, source }),
-        };
-
         Err(OsuError::Response {
             body,
+            route: route.to_owned(),
             source,
             status,
         })
     }
 }
 
-#[inline]
-fn parse_bytes<T: DeserializeOwned>(bytes: Bytes) -> OsuResult<T> {
-    serde_json::from_slice(&bytes).map_err(|source| {
-        let body = String::from_utf8_lossy(&bytes).into_owned();
+/// Duration specified by the `Retry-After` header in seconds, capped at
+/// [`MAX_RETRY_AFTER`] and defaulting to one second if the header is missing.
+fn retry_after(headers: &HeaderMap) -> Duration {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .map_or(Duration::from_secs(1), Duration::from_secs)
+        .min(MAX_RETRY_AFTER)
+}
+
+/// Exponential backoff starting at 500ms with up to 50% of random jitter
+/// so that many clients do not retry at the same time.
+fn server_error_backoff(attempt: usize) -> Duration {
+    let base = 500_u64 << attempt.min(10);
+
+    // Cheap source of randomness; good enough for jitter
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos() as u64);
+
+    Duration::from_millis(base + nanos % (base / 2 + 1))
+}
+
+/// The response body as string, truncated to at most [`MAX_PARSING_BODY_LEN`] bytes.
+fn truncated_body(bytes: &[u8]) -> String {
+    let mut body = String::from_utf8_lossy(bytes).into_owned();
+
+    if body.len() > MAX_PARSING_BODY_LEN {
+        let mut end = MAX_PARSING_BODY_LEN;
+
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        body.truncate(end);
+        body.push_str("...");
+    }
 
-        OsuError::Parsing { body, source }
-    })
+    body
 }
 
 fn clone_req(req: &HyperRequest<BodyBytes>) -> HyperRequest<BodyBytes> {
@@ -1,8 +1,9 @@
 use super::OsuRef;
 
 use serde::Deserialize;
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 use std::{error::Error, sync::Arc, time::Duration};
+use time::{Duration as TimeDuration, OffsetDateTime};
 use tokio::{
     sync::oneshot::{self, Receiver},
     time::sleep,
@@ -12,12 +13,25 @@ use tokio::{
 pub(super) struct Token {
     pub access: Option<String>,
     pub refresh: Option<String>,
+    pub expires_at: Option<OffsetDateTime>,
 }
 
 impl Token {
+    pub(super) fn to_public(&self) -> OsuToken {
+        OsuToken {
+            access_token: self
+                .access
+                .as_deref()
+                .map(|access| access.trim_start_matches("Bearer ").to_owned()),
+            refresh_token: self.refresh.clone(),
+        }
+    }
+
     pub(super) fn update(&mut self, response: TokenResponse) {
         self.access = Some(format!("Bearer {}", response.access_token));
         self.refresh = response.refresh_token;
+        self.expires_at =
+            Some(OffsetDateTime::now_utc() + TimeDuration::seconds(response.expires_in));
     }
 
     pub(super) fn update_worker(osu: Arc<OsuRef>, mut expire: i64, mut dropped_rx: Receiver<()>) {
@@ -34,30 +48,33 @@ impl Token {
                         let _ = expire_tx.send(());
                         return debug!("Osu dropped; exiting token update loop");
                     }
-                    token = Self::update_routine(Arc::clone(&osu), expire, expire_rx) => {
+                    expires_in = Self::update_routine(Arc::clone(&osu), expire, expire_rx) => {
                         let _ = expire_tx.send(());
                         debug!("Successfully acquired new token");
 
-                        expire = token.expires_in;
-                        osu.token.write().await.update(token);
+                        expire = expires_in;
                     }
                 }
             }
         });
     }
 
-    async fn update_routine(
-        osu: Arc<OsuRef>,
-        expire: i64,
-        mut expire_rx: Receiver<()>,
-    ) -> TokenResponse {
+    async fn update_routine(osu: Arc<OsuRef>, expire: i64, mut expire_rx: Receiver<()>) -> i64 {
         let osu_clone = Arc::clone(&osu);
+        let expiring = osu.token.read().await.access.clone();
+
         tokio::spawn(async move {
             tokio::select! {
                 _ = &mut expire_rx => {}
                 _ = sleep(Duration::from_secs(expire.max(0) as u64)) => {
-                    warn!("Acquiring new token took too long, removed current token");
-                    osu_clone.token.write().await.access.take();
+                    let mut token = osu_clone.token.write().await;
+
+                    // The token may have been refreshed due to a 401 response in the meantime
+                    if token.access == expiring {
+                        warn!("Acquiring new token took too long, removed current token");
+                        token.access.take();
+                        token.expires_at.take();
+                    }
                 }
             }
         });
@@ -71,13 +88,22 @@ impl Token {
         Token::request_loop(&osu).await
     }
 
-    // Acquire a new token through exponential backoff
-    async fn request_loop(osu: &OsuRef) -> TokenResponse {
+    // Acquire and store a new token through exponential backoff
+    async fn request_loop(osu: &OsuRef) -> i64 {
         let mut backoff = 400;
 
         loop {
-            match osu.request_token().await {
-                Ok(token) if token.token_type == "Bearer" => return token,
+            // Hold the lock while requesting so that a refresh due to
+            // a 401 response does not use the same refresh token
+            let mut current = osu.token.write().await;
+
+            match osu.request_token_with(current.refresh.as_deref()).await {
+                Ok(token) if token.token_type == "Bearer" => {
+                    let expires_in = token.expires_in;
+                    osu.update_token(&mut current, token);
+
+                    return expires_in;
+                }
                 Ok(token) => {
                     warn!(
                         r#"Failed to acquire new token, "{}" != "Bearer"; retry in {}ms"#,
@@ -99,12 +125,27 @@ impl Token {
                 }
             }
 
+            drop(current);
             sleep(Duration::from_millis(backoff)).await;
             backoff = (backoff * 2).min(60_000);
         }
     }
 }
 
+/// The current tokens of an [`Osu`](crate::Osu) client, see [`Osu::token`](crate::Osu::token).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OsuToken {
+    /// The access token that is being sent with requests.
+    ///
+    /// `None` if the previous token expired and no new one has been acquired yet.
+    pub access_token: Option<String>,
+    /// The refresh token to acquire a new access token.
+    ///
+    /// Only available if the client was authorized by a user through
+    /// [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    pub refresh_token: Option<String>,
+}
+
 #[inline]
 fn adjust_token_expire(expires_in: i64) -> i64 {
     expires_in - (expires_in as f64 * 0.05) as i64
@@ -112,12 +153,16 @@ fn adjust_token_expire(expires_in: i64) -> i64 {
 
 pub(super) enum AuthorizationKind {
     User(Authorization),
-    Client(Scope),
+    Client,
 }
 
-impl Default for AuthorizationKind {
-    fn default() -> Self {
-        Self::Client(Scope::Public)
+impl AuthorizationKind {
+    /// Scopes that are requested if none were specified through [`OsuBuilder::scopes`](crate::OsuBuilder::scopes).
+    pub(super) fn default_scopes(&self) -> Vec<Scope> {
+        match self {
+            Self::User(_) => vec![Scope::Identify, Scope::Public],
+            Self::Client => vec![Scope::Public],
+        }
     }
 }
 
@@ -135,15 +180,25 @@ pub(super) struct TokenResponse {
     pub token_type: String,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+/// OAuth scopes that can be requested for the client's token,
+/// see [`OsuBuilder::scopes`](crate::OsuBuilder::scopes).
+///
+/// For more info, check out <https://osu.ppy.sh/docs/index.html#scopes>
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Scope {
+    /// Send chat messages on the user's behalf
     ChatWrite,
+    /// Act as the owner of the client; only available for client credentials
     Delegate,
+    /// Create and edit forum posts on the user's behalf
     ForumWrite,
+    /// List of the user's friends, required for [`Osu::friends`](crate::Osu::friends)
     FriendsRead,
+    /// Read the user's public profile, required for [`Osu::own_data`](crate::Osu::own_data)
     Identify,
     Lazer,
+    /// Read public data on the user's behalf, required for most endpoints
     Public,
 }
 
@@ -160,3 +215,18 @@ impl Display for Scope {
         }
     }
 }
+
+pub(super) fn scopes_to_string(scopes: &[Scope]) -> String {
+    let mut iter = scopes.iter();
+    let mut s = String::new();
+
+    if let Some(scope) = iter.next() {
+        let _ = write!(s, "{scope}");
+
+        for scope in iter {
+            let _ = write!(s, " {scope}");
+        }
+    }
+
+    s
+}
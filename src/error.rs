@@ -1,3 +1,5 @@
+use crate::client::Scope;
+
 use hyper::{
     header::InvalidHeaderValue, http::Error as HttpError, Error as HyperError, StatusCode,
 };
@@ -38,6 +40,15 @@ pub enum OsuError {
     ChunkingResponse { source: HyperError },
     /// Failed to create the token header for a request
     CreatingTokenHeader { source: InvalidHeaderValue },
+    /// A parameter of the request was invalid so the request was not sent
+    InvalidParameter {
+        /// Name of the parameter
+        param: &'static str,
+        /// Why the value is invalid
+        reason: String,
+    },
+    /// The client's token was not requested with the scope required by the endpoint
+    MissingScope { scope: Scope },
     /// The API returned a 404
     NotFound,
     /// Attempted to make request without valid token
@@ -46,7 +57,14 @@ pub enum OsuError {
     /// There was an error while trying to use osu-db
     OsuDbError { source: osu_db::Error },
     /// Failed to deserialize response
-    Parsing { body: String, source: SerdeError },
+    Parsing {
+        /// The truncated response body, only captured if enabled through
+        /// [`OsuBuilder::capture_parsing_body`](crate::OsuBuilder::capture_parsing_body)
+        body: Option<String>,
+        /// Path of the requested route e.g. `rankings/osu/performance`
+        route: String,
+        source: SerdeError,
+    },
     /// Failed to parse a value
     ParsingValue { source: ParsingError },
     /// Failed to send request
@@ -56,6 +74,9 @@ pub enum OsuError {
     /// API returned an error
     Response {
         body: String,
+        /// Path of the requested route e.g. `users/2/osu`
+        route: String,
+        /// Contains the error message of the API, if any
         source: ApiError,
         status: StatusCode,
     },
@@ -81,6 +102,8 @@ impl StdError for OsuError {
             Self::BuilderMissingSecret => None,
             Self::ChunkingResponse { source } => Some(source),
             Self::CreatingTokenHeader { source } => Some(source),
+            Self::InvalidParameter { .. } => None,
+            Self::MissingScope { .. } => None,
             Self::NotFound => None,
             Self::NoToken => None,
             #[cfg(feature = "replay")]
@@ -112,6 +135,12 @@ impl fmt::Display for OsuError {
             Self::CreatingTokenHeader { .. } => {
                 f.write_str("failed to parse token for authorization header")
             }
+            Self::InvalidParameter { param, reason } => {
+                write!(f, "invalid value for the parameter `{}`: {}", param, reason)
+            }
+            Self::MissingScope { scope } => {
+                write!(f, "the endpoint requires the `{}` scope", scope)
+            }
             Self::NotFound => f.write_str(
                 "the osu!api returned a 404 implying a missing score, incorrect name, id, etc",
             ),
@@ -123,11 +152,30 @@ impl fmt::Display for OsuError {
             ),
             #[cfg(feature = "replay")]
             Self::OsuDbError { .. } => f.write_str("osu-db error"),
-            Self::Parsing { body, .. } => write!(f, "failed to deserialize response: {}", body),
+            Self::Parsing {
+                body: Some(body),
+                route,
+                ..
+            } => write!(f, "failed to deserialize response of `{}`: {}", route, body),
+            Self::Parsing { route, .. } => {
+                write!(f, "failed to deserialize response of `{}`", route)
+            }
             Self::ParsingValue { .. } => f.write_str("failed to parse value"),
             Self::Request { .. } => f.write_str("failed to send request"),
             Self::RequestTimeout => f.write_str("osu!api did not respond in time"),
-            Self::Response { status, .. } => write!(f, "response error, status {}", status),
+            Self::Response {
+                route,
+                source: ApiError { error: Some(msg) },
+                status,
+                ..
+            } => write!(
+                f,
+                "response error for `{}`, status {}: {}",
+                route, status, msg
+            ),
+            Self::Response { route, status, .. } => {
+                write!(f, "response error for `{}`, status {}", route, status)
+            }
             Self::ServiceUnavailable(body) => write!(
                 f,
                 "osu!api may be temporarily unavailable (received 503): {}",
@@ -164,6 +212,8 @@ impl From<ParsingError> for OsuError {
 /// Failed some TryFrom parsing
 #[derive(Debug)]
 pub enum ParsingError {
+    /// Failed to parse a String into a [`GameMode`](crate::model::GameMode)
+    GameMode(String),
     /// Failed to parse a u8 into a [`Genre`](crate::model::beatmap::Genre)
     Genre(u8),
     /// Failed to parse a String into a [`Grade`](crate::model::Grade)
@@ -189,6 +239,7 @@ impl StdError for ParsingError {}
 impl fmt::Display for ParsingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::GameMode(s) => write!(f, "failed to parse `{}` into GameMode", s),
             Self::Genre(n) => write!(f, "failed to parse {} into Genre", n),
             Self::Grade(s) => write!(f, "failed to parse `{}` into Grade", s),
             Self::Language(n) => write!(f, "failed to parse {} into Language", n),
@@ -201,3 +252,37 @@ impl fmt::Display for ParsingError {
         }
     }
 }
+
+/// A username does not follow osu!'s rules for usernames
+#[derive(Debug, PartialEq, Eq)]
+pub enum UsernameError {
+    /// The username contains both spaces and underscores
+    MixedSpacesAndUnderscores,
+    /// The username contains a character other than ASCII letters, digits,
+    /// spaces, or one of `-`, `_`, `[`, `]`
+    InvalidCharacter(char),
+    /// The username starts or ends with a space, or contains two consecutive spaces
+    InvalidSpaces,
+    /// The username is longer than 15 characters
+    TooLong(usize),
+    /// The username is shorter than 3 characters
+    TooShort(usize),
+}
+
+impl StdError for UsernameError {}
+
+impl fmt::Display for UsernameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MixedSpacesAndUnderscores => {
+                f.write_str("username must not contain both spaces and underscores")
+            }
+            Self::InvalidCharacter(c) => write!(f, "username contains invalid character `{}`", c),
+            Self::InvalidSpaces => f.write_str(
+                "username must not start or end with a space or contain consecutive spaces",
+            ),
+            Self::TooLong(len) => write!(f, "username is too long ({} > 15 characters)", len),
+            Self::TooShort(len) => write!(f, "username is too short ({} < 3 characters)", len),
+        }
+    }
+}
@@ -31,17 +31,28 @@
 //! - `beatmapsets/{mapset_id}`: The beatmapset including all of its difficulty beatmaps
 //! - `beatmapsets/events`: Various events around a beatmapset such as status, genre, or language updates, kudosu transfers, or new issues
 //! - `beatmapsets/search`: Search for beatmapsets; the same search as on the osu! website
+//! - `changelog`: Builds of osu! update streams including their changelog entries
+//! - `changelog/{stream}/{build}`: A specific build including its changelog entries, also available by build id
 //! - `comments`: Most recent comments and their replies up to two levels deep
+//! - `events`: The site-wide feed of recent events like achieved medals, ranks on a beatmap, or beatmapset status updates
 //! - `forums/topics/{topic_id}`: A forum topic and its posts
+//! - `friends`: List of the authenticated user's friends (requires OAuth)
 //! - `matches`: List of currently open multiplayer lobbies
 //! - `matches/{match_id}`: More specific data about a specific multiplayer lobby including participating players and occured events
 //! - `me[/{mode}]`: Detailed info about the authenticated user [in the specified mode] (requires OAuth)
 //! - `news`: Recent news
+//! - `news/{slug}`: A specific news post including its full content
 //! - `rankings/{mode}/{ranking_type}`: The global leaderboard of either performance points, ranked score, countries, or a spotlight
+//! - `rankings/kudosu`: The global leaderboard of total kudosu
+//! - `rooms`: List of multiplayer rooms, either playlists or realtime lobbies
+//! - `rooms/{room_id}`: A multiplayer room including its playlist items
+//! - `rooms/{room_id}/playlist/{playlist_id}/scores`: Scores on a playlist item of a multiplayer room
+//! - `rooms/{room_id}/playlist/{playlist_id}/scores/{score_id}`: A specific score on a playlist item of a multiplayer room
 //! - `users/{user_id}/{recent_activity}`: List of a user's recent events like achieved medals, ranks on a beatmaps, username changes, supporter status updates, beatmapset status updates, ...
-//! - `scores/{mode}/{score_id}`: A specific score including its beatmap, beatmapset, and user
+//! - `scores/[{mode}/]{score_id}`: A specific score including its beatmap, beatmapset, and user
 //! - `seasonal-backgrounds`: List of seasonal backgrounds i.e. their URL and artists
 //! - `spotlights`: List of overviews of all spotlights
+//! - `users`: Up to 50 users at once
 //! - `users/{user_id}[/{mode}]`: Detailed info about a user [in the specified mode]
 //! - `users/{user_id}/{beatmapsets/{map_type}`: List of beatmapsets either created, favourited, or most played by the user
 //! - `users/{user_id}/kudosu`: A user's recent kudosu transfers
@@ -112,6 +123,9 @@
 //! | `default` | Enable the `cache` feature |
 //! | `cache` | Cache username-user_id pairs so that usernames can be used on all user endpoints instead of only user ids | [dashmap](https://github.com/xacrimon/dashmap)
 //! | `metrics` | Provide a count of all request types the client makes with the function `Osu::metrics` returning a `prometheus::IntCounterVec` | [prometheus](https://github.com/tikv/rust-prometheus)
+//! | `log-unknown-fields` | Log a warning for API fields ignored by custom deserializers of structs, e.g. rankings or matches, to notice API additions early. Uses `tracing` if the `tracing` feature is enabled |
+//! | `raw-extra` | Capture unmodeled API fields of `UserCompact` and `NewsPost` in their `extra` field as `serde_json::Value`. The captured keys are unstable |
+//! | `tracing` | Wrap each request in a `tracing` span containing its route and method, and log the status and elapsed time of the response | [tracing](https://github.com/tokio-rs/tracing)
 //! | `rkyv` | Implement rkyv's `Archive`, `Deserialize`, and `Serialize` for most types, allowing for insanely fast (de)serializing. | [rkyv](https://github.com/rkyv/rkyv)
 //!
 
@@ -131,7 +145,7 @@ pub mod request;
 #[cfg(feature = "metrics")]
 mod metrics;
 
-pub use client::{Osu, OsuBuilder};
+pub use client::{Osu, OsuBuilder, OsuToken};
 
 #[macro_use]
 extern crate log;
@@ -145,12 +159,12 @@ pub mod prelude {
         client::Scope,
         error::OsuError,
         model::{
-            beatmap::*, comments::*, forum::*, kudosu::*, matches::*, news::*, ranking::*,
-            recent_event::*, score::*, seasonal_backgrounds::*, user::*, wiki::*, Cursor, GameMode,
-            GameMods, Grade,
+            beatmap::*, changelog::*, comments::*, forum::*, kudosu::*, matches::*, multiplayer::*,
+            news::*, ranking::*, recent_event::*, score::*, seasonal_backgrounds::*, user::*,
+            wiki::*, Cursor, GameMode, GameMods, Grade,
         },
         request::UserId,
-        Osu, OsuBuilder, OsuResult,
+        Osu, OsuBuilder, OsuResult, OsuToken,
     };
 
     pub use hyper::StatusCode;
@@ -18,17 +18,31 @@ pub(crate) struct Metrics {
     #[cfg(feature = "cache")]
     pub(crate) cache_size: IntCounter,
 
+    pub(crate) changelog: IntCounter,
+    pub(crate) changelog_build: IntCounter,
+
     pub(crate) comments: IntCounter,
 
+    pub(crate) events: IntCounter,
+
     pub(crate) forum_posts: IntCounter,
 
+    pub(crate) friends: IntCounter,
+
     pub(crate) osu_match: IntCounter,
     pub(crate) match_list: IntCounter,
 
+    pub(crate) multiplayer_score: IntCounter,
+    pub(crate) multiplayer_scores: IntCounter,
+    pub(crate) room: IntCounter,
+    pub(crate) rooms: IntCounter,
+
     pub(crate) news: IntCounter,
+    pub(crate) news_post: IntCounter,
 
     pub(crate) chart_rankings: IntCounter,
     pub(crate) country_rankings: IntCounter,
+    pub(crate) kudosu_rankings: IntCounter,
     pub(crate) performance_rankings: IntCounter,
     pub(crate) score_rankings: IntCounter,
     pub(crate) spotlights: IntCounter,
@@ -75,17 +89,31 @@ impl Metrics {
             #[cfg(feature = "cache")]
             cache_size: counters.with_label_values(&["Cached Username-UserId pairs"]),
 
+            changelog: counters.with_label_values(&["Changelog"]),
+            changelog_build: counters.with_label_values(&["Changelog build"]),
+
             comments: counters.with_label_values(&["Comments"]),
 
+            events: counters.with_label_values(&["Events"]),
+
             forum_posts: counters.with_label_values(&["Forum posts"]),
 
+            friends: counters.with_label_values(&["Friends"]),
+
             osu_match: counters.with_label_values(&["Matches"]),
             match_list: counters.with_label_values(&["Match list"]),
 
+            multiplayer_score: counters.with_label_values(&["Multiplayer score"]),
+            multiplayer_scores: counters.with_label_values(&["Multiplayer scores"]),
+            room: counters.with_label_values(&["Room"]),
+            rooms: counters.with_label_values(&["Rooms"]),
+
             news: counters.with_label_values(&["News"]),
+            news_post: counters.with_label_values(&["News post"]),
 
             chart_rankings: counters.with_label_values(&["Chart rankings"]),
             country_rankings: counters.with_label_values(&["Country rankings"]),
+            kudosu_rankings: counters.with_label_values(&["Kudosu rankings"]),
             performance_rankings: counters.with_label_values(&["Performance rankings"]),
             score_rankings: counters.with_label_values(&["Score rankings"]),
             spotlights: counters.with_label_values(&["Spotlights"]),
@@ -180,13 +180,21 @@ pub(crate) struct BeatmapDifficultyAttributesWrapper {
     archive(as = "Self")
 )]
 pub struct BeatmapDifficultyAttributes {
+    /// Maximum combo of the map
     pub max_combo: u32,
+    /// Star rating of the map
     #[serde(rename = "star_rating")]
     pub stars: f32,
+    /// Attributes specific to the mode of the map
     #[serde(flatten)]
     pub attrs: GameModeAttributes,
 }
 
+/// Mode-specific difficulty attributes of a beatmap.
+///
+/// The variant depends on the mode that the attributes were calculated for,
+/// i.e. either the map's own mode or the one specified through
+/// [`GetBeatmapDifficultyAttributes::mode`](crate::request::GetBeatmapDifficultyAttributes::mode).
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(
@@ -196,9 +204,12 @@ pub struct BeatmapDifficultyAttributes {
 )]
 #[serde(untagged)]
 pub enum GameModeAttributes {
+    /// Attributes of an osu!standard map
     Osu {
+        /// Approach rate after applying mods
         #[serde(rename = "approach_rate")]
         ar: f32,
+        /// Overall difficulty after applying mods
         #[serde(rename = "overall_difficulty")]
         od: f32,
         aim_difficulty: f32,
@@ -206,19 +217,26 @@ pub enum GameModeAttributes {
         slider_factor: f32,
         speed_difficulty: f32,
     },
+    /// Attributes of an osu!taiko map
     Taiko {
         stamina_difficulty: f32,
         rhythm_difficulty: f32,
         colour_difficulty: f32,
         peak_difficulty: f32,
+        /// Hit window for a great hit in milliseconds
         great_hit_window: f32,
     },
+    /// Attributes of an osu!catch map
     Catch {
+        /// Approach rate after applying mods
         #[serde(rename = "approach_rate")]
         ar: f32,
     },
+    /// Attributes of an osu!mania map
     Mania {
+        /// Hit window for a great hit in milliseconds
         great_hit_window: f32,
+        /// Multiplier applied to the score based on the mods
         score_multiplier: f32,
     },
 }
@@ -437,6 +455,7 @@ fn deser_mapset_user<'de, D: Deserializer<'de>>(d: D) -> Result<Option<UserCompa
                 is_moderator: None,
                 is_nat: None,
                 is_silenced: None,
+                kudosu: None,
                 loved_mapset_count: None,
                 medals: None,
                 monthly_playcounts: None,
@@ -449,8 +468,11 @@ fn deser_mapset_user<'de, D: Deserializer<'de>>(d: D) -> Result<Option<UserCompa
                 scores_first_count: None,
                 scores_recent_count: None,
                 statistics: None,
+                statistics_rulesets: None,
                 support_level: None,
                 pending_mapset_count: None,
+                #[cfg(feature = "raw-extra")]
+                extra: serde_json::Value::Object(Default::default()),
             }))
         }
 
@@ -856,6 +878,18 @@ pub enum BeatmapsetEvent {
         #[serde(rename = "beatmapset")]
         mapset: BeatmapsetCompact,
     },
+    NominationReset {
+        #[serde(rename = "id")]
+        event_id: u64,
+        comment: BeatmapsetCommentId,
+        #[serde(with = "serde_::datetime")]
+        #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+        created_at: OffsetDateTime,
+        user_id: u32,
+        #[serde(rename = "beatmapset")]
+        mapset: BeatmapsetCompact,
+        discussion: BeatmapsetDiscussion,
+    },
     NsfwToggle {
         #[serde(rename = "id")]
         event_id: u64,
@@ -908,6 +942,55 @@ pub enum BeatmapsetEvent {
     }
 }
 
+/// Type of a [`BeatmapsetEvent`], used to filter
+/// [`Osu::beatmapset_events`](crate::Osu::beatmapset_events).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BeatmapsetEventType {
+    Disqualify,
+    GenreEdit,
+    IssueReopen,
+    IssueResolve,
+    KudosuDeny,
+    KudosuGain,
+    KudosuLost,
+    LanguageEdit,
+    Love,
+    Nominate,
+    NominationReset,
+    NsfwToggle,
+    OwnerChange,
+    Rank,
+    Qualify,
+    TagsEdit,
+}
+
+impl Display for BeatmapsetEventType {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let kind = match self {
+            Self::Disqualify => "disqualify",
+            Self::GenreEdit => "genre_edit",
+            Self::IssueReopen => "issue_reopen",
+            Self::IssueResolve => "issue_resolve",
+            Self::KudosuDeny => "kudosu_deny",
+            Self::KudosuGain => "kudosu_gain",
+            Self::KudosuLost => "kudosu_lost",
+            Self::LanguageEdit => "language_edit",
+            Self::Love => "love",
+            Self::Nominate => "nominate",
+            Self::NominationReset => "nomination_reset",
+            Self::NsfwToggle => "nsfw_toggle",
+            Self::OwnerChange => "beatmap_owner_change",
+            Self::Rank => "rank",
+            Self::Qualify => "qualify",
+            Self::TagsEdit => "tags_edit",
+        };
+
+        f.write_str(kind)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -1152,6 +1235,9 @@ impl<'de> Visitor<'de> for BeatmapsetSearchParametersVisitor {
                 "_sort" => sort = Some(map.next_value()?),
                 "descending" => descending = Some(map.next_value()?),
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("BeatmapsetSearchParameters", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -1267,6 +1353,7 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
     fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
         let mut mapsets = None;
         let mut cursor = None;
+        let mut cursor_string = None;
         let mut params = None;
         let mut total = None;
 
@@ -1274,9 +1361,13 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
             match key {
                 "beatmapsets" => mapsets = Some(map.next_value()?),
                 "cursor" => cursor = map.next_value()?,
+                "cursor_string" => cursor_string = map.next_value()?,
                 "search" => params = Some(map.next_value()?),
                 "total" => total = Some(map.next_value()?),
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("BeatmapsetSearchResult", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -1287,7 +1378,7 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
         let total = total.ok_or_else(|| Error::missing_field("total"))?;
 
         Ok(BeatmapsetSearchResult {
-            cursor,
+            cursor: cursor.or(cursor_string),
             mapsets,
             params,
             total,
@@ -1473,10 +1564,13 @@ impl<'de> Visitor<'de> for HundredU32Visitor {
     }
 }
 
+/// A beatmap and how often a user played it, as requested through
+/// [`Osu::user_most_played`](crate::Osu::user_most_played).
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct MostPlayedMap {
+    /// How often the user played the map
     pub count: usize,
     #[serde(rename = "beatmap")]
     pub map: BeatmapCompact,
@@ -1551,7 +1645,7 @@ impl<'de> Visitor<'de> for super::EnumVisitor<RankStatus> {
     type Value = RankStatus;
 
     fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_str("an optional RankStatus i8")
+        f.write_str("an optional RankStatus i8 or string")
     }
 
     fn visit_str<E: Error>(self, s: &str) -> Result<Self::Value, E> {
@@ -1717,6 +1811,126 @@ fn flatten_description<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>
     d.deserialize_option(DescriptionVisitor)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_status_int_and_str() {
+        let statuses: Vec<RankStatus> =
+            serde_json::from_str(r#"[-2, -1, 0, 1, 2, 3, 4, "graveyard", "wip", "loved"]"#)
+                .unwrap();
+
+        let expected = [
+            RankStatus::Graveyard,
+            RankStatus::WIP,
+            RankStatus::Pending,
+            RankStatus::Ranked,
+            RankStatus::Approved,
+            RankStatus::Qualified,
+            RankStatus::Loved,
+            RankStatus::Graveyard,
+            RankStatus::WIP,
+            RankStatus::Loved,
+        ];
+
+        assert_eq!(statuses, expected);
+    }
+
+    #[test]
+    fn mapset_event_nomination_reset() {
+        let json = r#"{
+            "id": 1001,
+            "type": "nomination_reset",
+            "comment": {
+                "beatmap_discussion_id": 55,
+                "beatmap_discussion_post_id": 66,
+                "nominator_ids": [3, 4]
+            },
+            "created_at": "2022-01-02T00:00:00+00:00",
+            "user_id": 5,
+            "beatmapset": {
+                "artist": "Artist",
+                "covers": {
+                    "cover": "", "cover@2x": "", "card": "", "card@2x": "",
+                    "list": "", "list@2x": "", "slimcover": "", "slimcover@2x": ""
+                },
+                "creator": "mapper",
+                "favourite_count": 10,
+                "id": 7,
+                "nsfw": false,
+                "play_count": 1000,
+                "preview_url": "",
+                "source": "",
+                "status": "pending",
+                "title": "Title",
+                "user_id": 2,
+                "video": false
+            },
+            "discussion": {
+                "id": 55,
+                "beatmapset_id": 7,
+                "beatmap_id": 8,
+                "user_id": 5,
+                "deleted_by_id": null,
+                "message_type": "problem",
+                "parent_id": null,
+                "timestamp": 12345,
+                "resolved": false,
+                "can_be_resolved": true,
+                "can_grant_kudosu": true,
+                "created_at": "2022-01-01T00:00:00+00:00",
+                "updated_at": "2022-01-02T00:00:00+00:00",
+                "deleted_at": null,
+                "last_post_at": "2022-01-02T00:00:00+00:00",
+                "kudosu_denied": false,
+                "starting_post": {
+                    "id": 66,
+                    "beatmapset_discussion_id": 55,
+                    "user_id": 5,
+                    "last_editor_id": null,
+                    "deleted_by_id": null,
+                    "system": false,
+                    "message": "unsnapped object",
+                    "created_at": "2022-01-01T00:00:00+00:00",
+                    "updated_at": "2022-01-01T00:00:00+00:00",
+                    "deleted_at": null
+                }
+            }
+        }"#;
+
+        let event: BeatmapsetEvent = serde_json::from_str(json).unwrap();
+
+        match event {
+            BeatmapsetEvent::NominationReset {
+                event_id,
+                comment,
+                user_id,
+                mapset,
+                discussion,
+                ..
+            } => {
+                assert_eq!(event_id, 1001);
+                assert_eq!(comment.map_discussion_id, Some(55));
+                assert_eq!(comment.map_discussion_post_id, Some(66));
+                assert_eq!(user_id, 5);
+                assert_eq!(mapset.mapset_id, 7);
+                assert_eq!(discussion.discussion_id, 55);
+                assert_eq!(discussion.starting_post.post_id, 66);
+            }
+            other => panic!("expected nomination reset, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rank_status_invalid() {
+        assert!(serde_json::from_str::<RankStatus>("5").is_err());
+        assert!(serde_json::from_str::<RankStatus>("-3").is_err());
+        assert!(serde_json::from_str::<RankStatus>(r#""unranked""#).is_err());
+        assert!(RankStatus::try_from(5).is_err());
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "serialize")]
 mod serde_tests {
@@ -1779,4 +1993,13 @@ mod serde_tests {
 
         ser_de(search_result);
     }
+
+    #[test]
+    fn de_search_result_cursor_string() {
+        let json =
+            r#"{"beatmapsets":[],"cursor":null,"cursor_string":"eyJpZCI6MTAwMH0","total":0}"#;
+        let search_result: BeatmapsetSearchResult = serde_json::from_str(json).unwrap();
+
+        assert!(search_result.has_more());
+    }
 }
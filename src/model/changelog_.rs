@@ -0,0 +1,208 @@
+use super::serde_;
+use crate::prelude::Username;
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A build of an [`UpdateStream`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogBuild {
+    /// Unique identifier of the build
+    #[serde(rename = "id")]
+    pub build_id: u32,
+    /// The changes that were made in the build
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changelog_entries: Vec<ChangelogEntry>,
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub created_at: OffsetDateTime,
+    /// The version as it's displayed on the website
+    pub display_version: String,
+    /// The stream the build belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_stream: Option<UpdateStream>,
+    /// Amount of users that are currently on the build
+    pub users: u32,
+    /// The version of the build, e.g. `2022.101.0`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// A single change of a [`ChangelogBuild`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogEntry {
+    /// The affected area, e.g. `Gameplay` or `Reliability`
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub created_at: Option<OffsetDateTime>,
+    /// Unique identifier of the entry
+    #[serde(default, rename = "id", skip_serializing_if = "Option::is_none")]
+    pub entry_id: Option<u32>,
+    /// The kind of change, e.g. `add`, `fix`, or `misc`
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_pull_request_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_url: Option<String>,
+    /// The author of the change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_user: Option<GithubUser>,
+    /// Whether the change is considered notable
+    pub major: bool,
+    /// The repository of the change, e.g. `ppy/osu`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// The search parameters of a [`ChangelogListing`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogSearch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    pub limit: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// Builds of the changelog, see [`Osu::changelog`](crate::Osu::changelog)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogListing {
+    pub builds: Vec<ChangelogBuild>,
+    pub search: ChangelogSearch,
+    /// All available update streams
+    pub streams: Vec<UpdateStream>,
+}
+
+/// The GitHub account of the author of a [`ChangelogEntry`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct GithubUser {
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_url: Option<String>,
+    /// Identifier of the user on the osu! website, not on GitHub
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u32>,
+    /// The osu! username, if the GitHub account is linked to one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub osu_username: Option<Username>,
+}
+
+/// A release channel of osu!, e.g. `stable40` or `lazer`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct UpdateStream {
+    /// Unique identifier of the stream
+    #[serde(rename = "id")]
+    pub stream_id: u32,
+    /// The name as it's displayed on the website, e.g. `Stable`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub is_featured: bool,
+    /// The name as it's used in URLs, e.g. `stable40`
+    pub name: String,
+    /// Amount of users that are currently on the stream
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_listing() {
+        let json = r#"{
+            "builds": [{
+                "created_at": "2022-01-01T12:00:00+00:00",
+                "display_version": "2022.101.0",
+                "id": 6000,
+                "users": 1234,
+                "version": "2022.101.0",
+                "youtube_id": null,
+                "update_stream": {
+                    "id": 5,
+                    "name": "lazer",
+                    "display_name": "Lazer",
+                    "is_featured": false
+                },
+                "changelog_entries": [{
+                    "category": "Gameplay",
+                    "created_at": "2021-12-31T12:00:00+00:00",
+                    "github_pull_request_id": 123,
+                    "github_url": "https://github.com/ppy/osu/pull/123",
+                    "id": null,
+                    "major": true,
+                    "repository": "ppy/osu",
+                    "title": "Fix something",
+                    "type": "fix",
+                    "url": null,
+                    "github_user": {
+                        "display_name": "peppy",
+                        "github_url": "https://github.com/peppy",
+                        "id": 1,
+                        "osu_username": "peppy",
+                        "user_id": 2,
+                        "user_url": "https://osu.ppy.sh/users/2"
+                    }
+                }]
+            }],
+            "search": {
+                "from": null,
+                "limit": 21,
+                "max_id": null,
+                "stream": "lazer",
+                "to": null
+            },
+            "streams": [{
+                "id": 5,
+                "name": "lazer",
+                "display_name": "Lazer",
+                "is_featured": false,
+                "user_count": 5000
+            }]
+        }"#;
+
+        let listing: ChangelogListing = serde_json::from_str(json).unwrap();
+
+        let build = &listing.builds[0];
+        assert_eq!(build.build_id, 6000);
+        assert_eq!(build.update_stream.as_ref().unwrap().name, "lazer");
+
+        let entry = &build.changelog_entries[0];
+        assert_eq!(entry.entry_type, "fix");
+        assert_eq!(entry.entry_id, None);
+        assert_eq!(entry.github_user.as_ref().unwrap().user_id, Some(2));
+
+        assert_eq!(listing.search.stream.as_deref(), Some("lazer"));
+        assert_eq!(listing.streams[0].user_count, Some(5000));
+    }
+}
@@ -95,8 +95,12 @@ impl Eq for Comment {}
 // TODO
 // #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct CommentBundle {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) commentable_id: Option<u32>,
     /// ID of the object the comment is attached to
     pub commentable_meta: Vec<CommentableMeta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) commentable_type: Option<String>,
     /// List of comments ordered according to `sort`
     pub comments: Vec<Comment>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -107,6 +111,8 @@ pub struct CommentBundle {
     pub has_more_id: Option<u32>,
     /// Related comments; e.g. parent comments and nested replies
     pub included_comments: Vec<Comment>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) parent_id: Option<u32>,
     /// Pinned comments
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pinned_comments: Option<Vec<Comment>>,
@@ -136,11 +142,26 @@ impl CommentBundle {
 
     /// If [`has_more`](CommentBundle::has_more) is true, the API can provide the next set of comments and this method will request them.
     /// Otherwise, this method returns `None`.
-    #[inline]
+    ///
+    /// The next page is requested with the same commentable, parent, and sort as this bundle.
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<CommentBundle>> {
         debug_assert!(self.has_more == self.cursor.is_some());
 
-        Some(osu.comments().cursor(self.cursor.clone()?).await)
+        let mut req = osu.comments().sort(self.sort).cursor(self.cursor.clone()?);
+
+        if let Some(ref commentable_type) = self.commentable_type {
+            req = req.commentable_type(commentable_type.as_str());
+        }
+
+        if let Some(commentable_id) = self.commentable_id {
+            req = req.commentable_id(commentable_id);
+        }
+
+        if let Some(parent_id) = self.parent_id {
+            req = req.parent(parent_id);
+        }
+
+        Some(req.await)
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::request::Query;
 
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 /// A structure included in some API responses containing the parameters to get the next set of results.
 ///
@@ -9,6 +9,9 @@ use serde_json::Value;
 ///
 /// If there are no more results available, a cursor with a value of `None` is returned.
 ///
+/// The cursor is either a map of parameters or an opaque string as provided by
+/// the API's `cursor_string` format. Both are sent back the same way they were received.
+///
 /// Note that sort option should also be specified for it to work.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -20,28 +23,81 @@ pub struct Cursor {
 }
 
 impl Cursor {
-    /// Create a cursor
+    /// Create a cursor, e.g. from a value that was previously stored through [`Cursor::as_value`].
+    ///
+    /// The value must be either a map of strings, numbers, or booleans, or a string
+    /// i.e. the same shape as provided by the API.
     #[inline]
     pub fn new(cursor: Value) -> Self {
         Self { cursor }
     }
 
+    /// The raw value of the cursor as it was provided by the API.
+    ///
+    /// Storing this value and passing it to [`Cursor::new`] later on
+    /// allows resuming pagination, e.g. after a restart.
+    #[inline]
+    pub fn as_value(&self) -> &Value {
+        &self.cursor
+    }
+
     pub(crate) fn push_to_query(&self, query: &mut Query) {
-        if let Value::Object(ref map) = self.cursor {
-            for (key, value) in map {
-                let key = format!("cursor[{}]", key);
-
-                match value {
-                    Value::Bool(v) => query.push(key.as_str(), v),
-                    Value::Number(v) => query.push(key.as_str(), v),
-                    Value::String(v) => query.push(key.as_str(), v),
-                    Value::Null | Value::Array(_) | Value::Object(_) => {
-                        unreachable!("cursor fields expected to be a string, number, or boolean")
-                    }
+        match self.cursor {
+            Value::Object(ref map) => Self::push_map_to_query(map, query),
+            Value::String(ref cursor_string) => query.push("cursor_string", cursor_string),
+            _ => unreachable!("cursor is expected to be a map or a string"),
+        }
+    }
+
+    fn push_map_to_query(map: &Map<String, Value>, query: &mut Query) {
+        for (key, value) in map {
+            let key = format!("cursor[{}]", key);
+
+            match value {
+                Value::Bool(v) => query.push(key.as_str(), v),
+                Value::Number(v) => query.push(key.as_str(), v),
+                Value::String(v) => query.push(key.as_str(), v),
+                Value::Null | Value::Array(_) | Value::Object(_) => {
+                    unreachable!("cursor fields expected to be a string, number, or boolean")
                 }
             }
-        } else {
-            unreachable!("cursor is expected to be a map");
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_map() {
+        let cursor: Cursor =
+            serde_json::from_str(r#"{"published_at":"2021-08-06","id":1000}"#).unwrap();
+
+        let mut query = Query::new();
+        cursor.push_to_query(&mut query);
+        let query = query.to_string();
+
+        assert!(query.contains("cursor[published_at]=2021-08-06"));
+        assert!(query.contains("cursor[id]=1000"));
+    }
+
+    #[test]
+    fn cursor_string() {
+        let cursor: Cursor = serde_json::from_str(r#""eyJpZCI6MTAwMH0""#).unwrap();
+
+        let mut query = Query::new();
+        cursor.push_to_query(&mut query);
+
+        assert_eq!(query.to_string(), "?cursor_string=eyJpZCI6MTAwMH0");
+    }
+
+    #[test]
+    fn cursor_value_roundtrip() {
+        let cursor: Cursor = serde_json::from_str(r#"{"id":1000}"#).unwrap();
+        let stored = cursor.as_value().to_string();
+
+        let value = serde_json::from_str(&stored).unwrap();
+        assert_eq!(Cursor::new(value), cursor);
+    }
+}
@@ -1,4 +1,5 @@
 use super::{serde_, Cursor};
+use crate::{Osu, OsuResult};
 
 use serde::{
     de::{Deserializer, Error, IgnoredAny, MapAccess, Visitor},
@@ -17,6 +18,8 @@ use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 pub struct ForumPosts {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cursor: Option<Cursor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_string: Option<Cursor>,
     pub posts: Vec<ForumPost>,
     pub search: ForumPostsSearch,
     pub topic: ForumTopic,
@@ -26,12 +29,33 @@ impl ForumPosts {
     /// Checks whether the cursor field is `Some` which in turn
     /// can be used to retrieve the next set of posts.
     ///
-    /// The next set can then be retrieved by providing this
-    /// [`Cursor`] to [`GetForumPosts::cursor`](crate::request::GetForumPosts::cursor).
+    /// The next set can then be retrieved through [`get_next`](ForumPosts::get_next)
+    /// or by providing this [`Cursor`] to [`GetForumPosts::cursor`](crate::request::GetForumPosts::cursor).
     /// Be sure all other parameters stay the same.
     #[inline]
     pub fn has_more(&self) -> bool {
-        self.cursor.is_some()
+        self.cursor.is_some() || self.cursor_string.is_some()
+    }
+
+    /// If [`has_more`](ForumPosts::has_more) is true, the API can provide the next set of posts and this method will request them.
+    /// Otherwise, this method returns `None`.
+    ///
+    /// The next set of posts keeps the limit and whether posts are sorted ascending or descending.
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<ForumPosts>> {
+        let cursor = self.cursor.as_ref().or(self.cursor_string.as_ref())?;
+
+        let req = osu
+            .forum_posts(self.topic.topic_id)
+            .limit(self.search.limit as usize)
+            .cursor(cursor.clone());
+
+        // A cursor of ascending posts can't be used for descending posts and vice versa
+        let req = match self.search.sort.as_str() {
+            "id_desc" => req.sort_descending(),
+            _ => req.sort_ascending(),
+        };
+
+        Some(req.await)
     }
 }
 
@@ -128,6 +152,9 @@ impl<'de> Visitor<'de> for ForumPostVisitor {
                 "topic_id" => topic_id = Some(map.next_value()?),
                 "user_id" => user_id = Some(map.next_value()?),
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("ForumPost", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
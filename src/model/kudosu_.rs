@@ -14,8 +14,20 @@ use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
     archive(as = "Self")
 )]
 pub enum KudosuAction {
+    #[serde(rename = "allow_kudosu.give")]
+    AllowKudosuGive,
+    #[serde(rename = "deny_kudosu.reset")]
+    DenyKudosuReset,
+    #[serde(rename = "give")]
+    Give,
+    #[serde(rename = "recalculate.give")]
+    RecalculateGive,
     #[serde(rename = "recalculate.reset")]
     RecalculateReset,
+    #[serde(rename = "reset")]
+    Reset,
+    #[serde(rename = "revoke")]
+    Revoke,
     #[serde(rename = "vote.give")]
     VoteGive,
     #[serde(rename = "vote.revoke")]
@@ -33,12 +45,14 @@ pub struct KudosuGiver {
     pub username: Username,
 }
 
+/// An entry of a user's kudosu history, as requested through
+/// [`Osu::kudosu`](crate::Osu::kudosu).
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct KudosuHistory {
     pub id: u32,
-    /// Either `give`, `reset`, or `revoke`.
+    /// The kind of kudosu exchange e.g. a vote or a recalculation.
     pub action: KudosuAction,
     pub amount: i32,
     // pub details: _; // TODO
@@ -239,6 +239,9 @@ impl<'de> Visitor<'de> for MatchEventVisitor {
                 "type" => kind = Some(map.next_value()?),
                 "match_name" => match_name = Some(map.next_value()?),
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("MatchEvent", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -474,9 +477,21 @@ impl MatchList {
 
     /// If [`has_more`](MatchList::has_more) is true, the API can provide the next set of matches and this method will request them.
     /// Otherwise, this method returns `None`.
-    #[inline]
+    ///
+    /// The next set of matches keeps the limit and the order of match ids.
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<MatchList>> {
-        Some(osu.osu_matches().cursor(self.cursor.clone()?).await)
+        let req = osu
+            .osu_matches()
+            .limit(self.params.limit)
+            .cursor(self.cursor.clone()?);
+
+        // The cursor points at a match id so it must be walked in the same direction
+        let req = match self.params.sort.as_str() {
+            "id_asc" => req.sort_ascending(),
+            _ => req.sort_descending(),
+        };
+
+        Some(req.await)
     }
 }
 
@@ -548,6 +563,9 @@ impl<'de> Visitor<'de> for MatchScoreVisitor {
                 "team" => team = Some(map.next_value()?),
                 "user_id" => user_id = Some(map.next_value()?),
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("MatchScore", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -814,7 +832,7 @@ impl OsuMatch {
     pub fn has_previous(&self) -> bool {
         self.events
             .first()
-            .map_or(false, |event| self.first_event_id != event.event_id())
+            .is_some_and(|event| self.first_event_id != event.event_id())
     }
 
     /// Get the [`OsuMatch`] containing only data before some event id.
@@ -904,6 +922,9 @@ impl<'de> Visitor<'de> for OsuMatchVisitor {
                     users = Some(user_map);
                 }
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("OsuMatch", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -168,10 +168,12 @@ mod serde_;
 mod rkyv_impls;
 
 pub(crate) mod beatmap_;
+pub(crate) mod changelog_;
 pub(crate) mod comments_;
 pub(crate) mod forum_;
 pub(crate) mod kudosu_;
 pub(crate) mod matches_;
+pub(crate) mod multiplayer_;
 pub(crate) mod news_;
 pub(crate) mod ranking_;
 pub(crate) mod recent_event_;
@@ -186,10 +188,17 @@ pub mod beatmap {
         Beatmap, BeatmapCompact, BeatmapDifficultyAttributes, Beatmapset, BeatmapsetAvailability,
         BeatmapsetCommentEdit, BeatmapsetCommentId, BeatmapsetCommentKudosuGain,
         BeatmapsetCommentNominate, BeatmapsetCommentOwnerChange, BeatmapsetCompact,
-        BeatmapsetCovers, BeatmapsetDiscussion, BeatmapsetEvent, BeatmapsetEvents, BeatmapsetHype,
-        BeatmapsetNominations, BeatmapsetPost, BeatmapsetReviewsConfig, BeatmapsetSearchResult,
-        BeatmapsetSearchSort, BeatmapsetVote, FailTimes, GameModeAttributes, Genre, Language,
-        MostPlayedMap, RankStatus,
+        BeatmapsetCovers, BeatmapsetDiscussion, BeatmapsetEvent, BeatmapsetEventType,
+        BeatmapsetEvents, BeatmapsetHype, BeatmapsetNominations, BeatmapsetPost,
+        BeatmapsetReviewsConfig, BeatmapsetSearchResult, BeatmapsetSearchSort, BeatmapsetVote,
+        FailTimes, GameModeAttributes, Genre, Language, MostPlayedMap, RankStatus,
+    };
+}
+
+/// Changelog related types
+pub mod changelog {
+    pub use super::changelog_::{
+        ChangelogBuild, ChangelogEntry, ChangelogListing, ChangelogSearch, GithubUser, UpdateStream,
     };
 }
 
@@ -216,28 +225,40 @@ pub mod matches {
     };
 }
 
+/// Multiplayer room related types
+pub mod multiplayer {
+    pub use super::multiplayer_::{
+        MultiplayerScore, MultiplayerScores, MultiplayerScoresParams, MultiplayerScoresSort,
+        PlaylistItem, Room, RoomMode, RoomStatus,
+    };
+}
+
 /// News related types
 pub mod news {
-    pub use super::news_::{News, NewsPost, NewsSearch, NewsSidebar};
+    pub use super::news_::{News, NewsNavigation, NewsPost, NewsSearch, NewsSidebar};
 }
 
 /// Ranking related types
 pub mod ranking {
     pub use super::ranking_::{
-        ChartRankings, CountryRanking, CountryRankings, Rankings, Spotlight,
+        ChartRankings, CountryRanking, CountryRankings, ManiaVariant, Rankings, Spotlight,
+        SpotlightType,
     };
 }
 
 /// User event related types
 pub mod recent_event {
     pub use super::recent_event_::{
-        EventBeatmap, EventBeatmapset, EventType, EventUser, RecentEvent,
+        EventBeatmap, EventBeatmapset, EventType, EventUser, Events, RecentEvent,
     };
 }
 
 /// Score related types
 pub mod score {
-    pub use super::score_::{BeatmapUserScore, Score, ScoreStatistics, ScoreWeight};
+    pub use super::score_::{
+        BeatmapScores, BeatmapUserScore, PerformanceProvider, Score, ScorePerformance,
+        ScoreStatistics, ScoreWeight,
+    };
 }
 
 /// Seasonal background related types
@@ -248,9 +269,11 @@ pub mod seasonal_backgrounds {
 /// User related types
 pub mod user {
     pub use super::user_::{
-        AccountHistory, Badge, CountryCode, GradeCounts, Group, HistoryType, Medal, MedalCompact,
+        normalize_username, username_eq_ignore_case, validate_username, AccountHistory, Badge,
+        CountryCode, GradeCounts, Group, HistoryType, MapsetType, Medal, MedalCompact,
         MonthlyCount, Playstyle, ProfileBanner, ProfilePage, User, UserCompact, UserCover,
-        UserHighestRank, UserKudosu, UserLevel, UserPage, UserStatistics, Username,
+        UserHighestRank, UserKudosu, UserLevel, UserPage, UserStatistics, UserStatisticsRulesets,
+        Username,
     };
 }
 
@@ -279,6 +302,13 @@ pub mod rkyv {
         FailTimesResolver, GameModeAttributesResolver, MostPlayedMapResolver, RankStatusResolver,
     };
 
+    pub use super::changelog_::{
+        ArchivedChangelogBuild, ArchivedChangelogEntry, ArchivedChangelogListing,
+        ArchivedChangelogSearch, ArchivedGithubUser, ArchivedUpdateStream, ChangelogBuildResolver,
+        ChangelogEntryResolver, ChangelogListingResolver, ChangelogSearchResolver,
+        GithubUserResolver, UpdateStreamResolver,
+    };
+
     pub use super::comments_::{
         ArchivedComment, ArchivedCommentableMeta, CommentResolver, CommentSortResolver,
         CommentableMetaResolver,
@@ -298,14 +328,22 @@ pub mod rkyv {
 
     pub use super::mode::GameModeResolver;
 
+    pub use super::multiplayer_::{
+        ArchivedMultiplayerScore, ArchivedMultiplayerScoresParams, ArchivedMultiplayerScoresSort,
+        ArchivedPlaylistItem, ArchivedRoom, MultiplayerScoreResolver,
+        MultiplayerScoresParamsResolver, MultiplayerScoresSortResolver, PlaylistItemResolver,
+        RoomResolver,
+    };
+
     pub use super::news_::{
-        ArchivedNewsPost, ArchivedNewsSidebar, NewsPostResolver, NewsSidebarResolver,
+        ArchivedNewsNavigation, ArchivedNewsPost, ArchivedNewsSidebar, NewsNavigationResolver,
+        NewsPostResolver, NewsSidebarResolver,
     };
 
     pub use super::ranking_::{
         ArchivedChartRankings, ArchivedCountryRanking, ArchivedCountryRankings, ArchivedRankings,
-        ArchivedSpotlight, ChartRankingsResolver, CountryRankingResolver, CountryRankingsResolver,
-        RankingsResolver, SpotlightResolver,
+        ArchivedSpotlight, ArchivedSpotlightType, ChartRankingsResolver, CountryRankingResolver,
+        CountryRankingsResolver, RankingsResolver, SpotlightResolver, SpotlightTypeResolver,
     };
 
     pub use super::recent_event_::{
@@ -315,8 +353,8 @@ pub mod rkyv {
     };
 
     pub use super::score_::{
-        ArchivedBeatmapUserScore, ArchivedScore, BeatmapUserScoreResolver, ScoreResolver,
-        ScoreStatisticsResolver, ScoreWeightResolver,
+        ArchivedBeatmapScores, ArchivedBeatmapUserScore, ArchivedScore, BeatmapScoresResolver,
+        BeatmapUserScoreResolver, ScoreResolver, ScoreStatisticsResolver, ScoreWeightResolver,
     };
 
     pub use super::seasonal_backgrounds_::{
@@ -328,11 +366,12 @@ pub mod rkyv {
         AccountHistoryResolver, ArchivedAccountHistory, ArchivedBadge, ArchivedGroup,
         ArchivedMedal, ArchivedMedalCompact, ArchivedMonthlyCount, ArchivedProfileBanner,
         ArchivedUser, ArchivedUserCompact, ArchivedUserCover, ArchivedUserHighestRank,
-        ArchivedUserPage, ArchivedUserStatistics, BadgeResolver, GradeCountsResolver,
-        GroupResolver, HistoryTypeResolver, MedalCompactResolver, MedalResolver,
-        MonthlyCountResolver, PlaystyleResolver, ProfileBannerResolver, ProfilePageResolver,
-        UserCompactResolver, UserCoverResolver, UserHighestRankResolver, UserKudosuResolver,
-        UserLevelResolver, UserPageResolver, UserResolver, UserStatisticsResolver,
+        ArchivedUserPage, ArchivedUserStatistics, ArchivedUserStatisticsRulesets, BadgeResolver,
+        GradeCountsResolver, GroupResolver, HistoryTypeResolver, MedalCompactResolver,
+        MedalResolver, MonthlyCountResolver, PlaystyleResolver, ProfileBannerResolver,
+        ProfilePageResolver, UserCompactResolver, UserCoverResolver, UserHighestRankResolver,
+        UserKudosuResolver, UserLevelResolver, UserPageResolver, UserResolver,
+        UserStatisticsResolver, UserStatisticsRulesetsResolver,
     };
 
     pub use super::wiki_::{ArchivedWikiPage, WikiPageResolver};
@@ -343,8 +382,34 @@ pub use grade::Grade;
 pub use mode::GameMode;
 pub use mods::GameMods;
 
+use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
 
+/// Deserialize a model from the raw JSON body of an API response, e.g. one that was stored earlier.
+///
+/// This goes through the same deserialization as responses of the [`Osu`](crate::Osu)
+/// client, including custom handling like the flattened user statistics of
+/// [`Rankings`](crate::model::ranking::Rankings), but does not require a client.
+///
+/// Data that the client fills in based on the request itself is not available.
+/// For example, the `mode` of [`Rankings`](crate::model::ranking::Rankings) is unknown
+/// so [`Rankings::get_next`](crate::model::ranking::Rankings::get_next) returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use rosu_v2::model::{from_bytes, ranking::Rankings};
+///
+/// let json = br#"{"cursor":{"page":2},"ranking":[],"total":10000}"#;
+/// let rankings: Rankings = from_bytes(json).unwrap();
+///
+/// assert_eq!(rankings.total, 10_000);
+/// ```
+#[inline]
+pub fn from_bytes<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(bytes)
+}
+
 struct EnumVisitor<T>(PhantomData<T>);
 
 impl<T> EnumVisitor<T> {
@@ -1,8 +1,10 @@
+use crate::error::{OsuError, ParsingError};
+
 use serde::{
     de::{Error, Unexpected, Visitor},
     Deserialize, Deserializer,
 };
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
@@ -26,6 +28,43 @@ pub enum GameMode {
     Mania = 3,
 }
 
+impl GameMode {
+    /// Get the mode of the given ruleset id, i.e. `0` for osu!standard up to `3` for osu!mania.
+    ///
+    /// Returns `None` if the id does not belong to a mode.
+    /// Unlike the `From<u8>` implementation, invalid ids are not turned into [`GameMode::Osu`].
+    #[inline]
+    pub fn from_ruleset_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(GameMode::Osu),
+            1 => Some(GameMode::Taiko),
+            2 => Some(GameMode::Catch),
+            3 => Some(GameMode::Mania),
+            _ => None,
+        }
+    }
+
+    /// The ruleset id of the mode, i.e. `0` for osu!standard up to `3` for osu!mania.
+    #[inline]
+    pub fn as_ruleset_id(self) -> u8 {
+        self as u8
+    }
+
+    /// The name of the mode as used by the API in routes and URLs,
+    /// i.e. `"osu"`, `"taiko"`, `"fruits"`, or `"mania"`.
+    ///
+    /// This is also what the `Display` implementation produces.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Osu => "osu",
+            Self::Taiko => "taiko",
+            Self::Catch => "fruits",
+            Self::Mania => "mania",
+        }
+    }
+}
+
 impl From<u8> for GameMode {
     #[inline]
     fn from(mode: u8) -> Self {
@@ -49,12 +88,27 @@ impl Default for GameMode {
 impl fmt::Display for GameMode {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Osu => f.write_str("osu"),
-            Self::Taiko => f.write_str("taiko"),
-            Self::Catch => f.write_str("fruits"),
-            Self::Mania => f.write_str("mania"),
-        }
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = OsuError;
+
+    /// Parse the mode from its name or ruleset id, ignoring case.
+    ///
+    /// Accepted are `"osu"`, `"osu!"`, `"std"`, `"standard"`, `"taiko"`, `"tko"`,
+    /// `"fruits"`, `"ctb"`, `"catch"`, `"mania"`, `"mna"`, as well as `"0"` to `"3"`.
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        let mode = match mode.to_lowercase().as_str() {
+            "0" | "osu" | "osu!" | "std" | "standard" => Self::Osu,
+            "1" | "taiko" | "tko" => Self::Taiko,
+            "2" | "fruits" | "ctb" | "catch" => Self::Catch,
+            "3" | "mania" | "mna" => Self::Mania,
+            _ => return Err(ParsingError::GameMode(mode.to_owned()).into()),
+        };
+
+        Ok(mode)
     }
 }
 
@@ -69,6 +123,7 @@ impl<'de> Visitor<'de> for ModeVisitor {
     }
 
     fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        // Only the exact values of the API; aliases are left to `FromStr`
         let mode = match v {
             "0" | "osu" | "osu!" => GameMode::Osu,
             "1" | "taiko" | "tko" => GameMode::Taiko,
@@ -114,3 +169,88 @@ impl serde::Serialize for GameMode {
         s.serialize_u8(*self as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_from_str() {
+        let cases = [
+            (
+                GameMode::Osu,
+                ["0", "osu", "osu!", "std", "standard"].as_slice(),
+            ),
+            (GameMode::Taiko, ["1", "taiko", "tko"].as_slice()),
+            (GameMode::Catch, ["2", "fruits", "ctb", "catch"].as_slice()),
+            (GameMode::Mania, ["3", "mania", "mna"].as_slice()),
+        ];
+
+        for (mode, names) in cases {
+            for name in names {
+                assert_eq!(name.parse::<GameMode>().unwrap(), mode, "{}", name);
+            }
+        }
+
+        assert_eq!("CtB".parse::<GameMode>().unwrap(), GameMode::Catch);
+    }
+
+    #[test]
+    fn mode_from_str_invalid() {
+        for name in ["", "4", "-1", "osu!mania", "catch the beat"] {
+            let err = name.parse::<GameMode>().unwrap_err();
+
+            assert!(
+                matches!(err, OsuError::ParsingValue { source: ParsingError::GameMode(ref s) } if s == name),
+                "{:?}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn mode_deserialize_exact() {
+        let modes: Vec<GameMode> =
+            serde_json::from_str(r#"["osu", "taiko", "fruits", "mania", 2]"#).unwrap();
+
+        assert_eq!(
+            modes,
+            [
+                GameMode::Osu,
+                GameMode::Taiko,
+                GameMode::Catch,
+                GameMode::Mania,
+                GameMode::Catch
+            ]
+        );
+
+        for name in [r#""std""#, r#""CtB""#, r#""catch""#] {
+            assert!(serde_json::from_str::<GameMode>(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn mode_from_ruleset_id() {
+        assert_eq!(GameMode::from_ruleset_id(0), Some(GameMode::Osu));
+        assert_eq!(GameMode::from_ruleset_id(1), Some(GameMode::Taiko));
+        assert_eq!(GameMode::from_ruleset_id(2), Some(GameMode::Catch));
+        assert_eq!(GameMode::from_ruleset_id(3), Some(GameMode::Mania));
+        assert_eq!(GameMode::from_ruleset_id(4), None);
+    }
+
+    #[test]
+    fn mode_display_roundtrip() {
+        let modes = [
+            (GameMode::Osu, "osu"),
+            (GameMode::Taiko, "taiko"),
+            (GameMode::Catch, "fruits"),
+            (GameMode::Mania, "mania"),
+        ];
+
+        for (mode, name) in modes {
+            assert_eq!(mode.to_string(), name);
+            assert_eq!(name.parse::<GameMode>().unwrap(), mode);
+            assert_eq!(GameMode::from_ruleset_id(mode.as_ruleset_id()), Some(mode));
+        }
+    }
+}
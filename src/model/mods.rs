@@ -271,6 +271,81 @@ impl GameMods {
             1.0
         }
     }
+
+    /// Returns the first pair of contained mods that cannot be used together, if any.
+    ///
+    /// The rules are those of osu!stable and don't depend on the mode:
+    /// - `DT` with `HT` and `EZ` with `HR`
+    /// - `FI` with `HD` or `FL`
+    /// - any two of `NF`, `SD`, `RX`, and `AP`
+    /// - `SO` with `AP`
+    /// - `AT` with `CN` and either of them with `NF`, `SD`, `RX`, `AP`, or `SO`
+    /// - more than one key mod
+    ///
+    /// Since `NC` contains `DT` and `PF` contains `SD`, they are covered by those.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::GameMods;
+    ///
+    /// let mods = GameMods::Hidden | GameMods::NightCore | GameMods::HalfTime;
+    /// assert_eq!(
+    ///     mods.incompatible(),
+    ///     Some((GameMods::DoubleTime, GameMods::HalfTime))
+    /// );
+    /// assert_eq!((GameMods::Hidden | GameMods::HardRock).incompatible(), None);
+    /// ```
+    pub fn incompatible(self) -> Option<(GameMods, GameMods)> {
+        const PAIRS: [(GameMods, GameMods); 22] = [
+            (GameMods::DoubleTime, GameMods::HalfTime),
+            (GameMods::Easy, GameMods::HardRock),
+            (GameMods::Hidden, GameMods::FadeIn),
+            (GameMods::FadeIn, GameMods::Flashlight),
+            (GameMods::NoFail, GameMods::SuddenDeath),
+            (GameMods::NoFail, GameMods::Relax),
+            (GameMods::NoFail, GameMods::Autopilot),
+            (GameMods::Relax, GameMods::Autopilot),
+            (GameMods::Relax, GameMods::SuddenDeath),
+            (GameMods::Autopilot, GameMods::SuddenDeath),
+            (GameMods::SpunOut, GameMods::Autopilot),
+            (GameMods::Autoplay, GameMods::Cinema),
+            (GameMods::Autoplay, GameMods::NoFail),
+            (GameMods::Autoplay, GameMods::SuddenDeath),
+            (GameMods::Autoplay, GameMods::Relax),
+            (GameMods::Autoplay, GameMods::Autopilot),
+            (GameMods::Autoplay, GameMods::SpunOut),
+            (GameMods::Cinema, GameMods::NoFail),
+            (GameMods::Cinema, GameMods::SuddenDeath),
+            (GameMods::Cinema, GameMods::Relax),
+            (GameMods::Cinema, GameMods::Autopilot),
+            (GameMods::Cinema, GameMods::SpunOut),
+        ];
+
+        PAIRS
+            .iter()
+            .copied()
+            .find(|&(a, b)| self.contains(a) && self.contains(b))
+            .or_else(|| {
+                let mut keys = self.iter().filter(|m| m.has_key_mod().is_some());
+
+                keys.next().zip(keys.next())
+            })
+    }
+
+    /// Checks whether the contained mods can be used together, see [`incompatible`](GameMods::incompatible).
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::GameMods;
+    ///
+    /// assert!((GameMods::Hidden | GameMods::DoubleTime).is_valid());
+    /// assert!(!(GameMods::Easy | GameMods::HardRock).is_valid());
+    /// assert!(!(GameMods::Key4 | GameMods::Key7).is_valid());
+    /// ```
+    #[inline]
+    pub fn is_valid(self) -> bool {
+        self.incompatible().is_none()
+    }
 }
 
 impl fmt::Display for GameMods {
@@ -603,6 +678,56 @@ mod tests {
         assert!(GameMods::from_str("HHDR").is_err());
     }
 
+    #[test]
+    fn deserialize_mods() {
+        let mods: GameMods = serde_json::from_str(r#"["HD","HR"]"#).unwrap();
+        assert_eq!(mods, GameMods::Hidden | GameMods::HardRock);
+
+        let mods: GameMods = serde_json::from_str("24").unwrap();
+        assert_eq!(mods, GameMods::Hidden | GameMods::HardRock);
+
+        let mods: GameMods = serde_json::from_str("[]").unwrap();
+        assert!(mods.is_empty());
+        assert_eq!(mods.to_string(), "NM");
+    }
+
+    #[test]
+    fn incompatible_mods() {
+        assert_eq!(GameMods::NoMod.incompatible(), None);
+        assert_eq!(
+            (GameMods::Perfect | GameMods::NoFail).incompatible(),
+            Some((GameMods::NoFail, GameMods::SuddenDeath))
+        );
+        assert_eq!(
+            (GameMods::Key4 | GameMods::Key7 | GameMods::Hidden).incompatible(),
+            Some((GameMods::Key4, GameMods::Key7))
+        );
+        assert_eq!(
+            (GameMods::Hidden | GameMods::FadeIn).incompatible(),
+            Some((GameMods::Hidden, GameMods::FadeIn))
+        );
+        assert_eq!(
+            (GameMods::Autoplay | GameMods::Perfect).incompatible(),
+            Some((GameMods::Autoplay, GameMods::SuddenDeath))
+        );
+        assert_eq!(
+            (GameMods::Cinema | GameMods::NoFail).incompatible(),
+            Some((GameMods::Cinema, GameMods::NoFail))
+        );
+        assert_eq!(
+            (GameMods::Autopilot | GameMods::Perfect).incompatible(),
+            Some((GameMods::Autopilot, GameMods::SuddenDeath))
+        );
+        assert_eq!(
+            (GameMods::Relax | GameMods::SuddenDeath).incompatible(),
+            Some((GameMods::Relax, GameMods::SuddenDeath))
+        );
+        assert_eq!(
+            (GameMods::Hidden | GameMods::HardRock | GameMods::Flashlight).incompatible(),
+            None
+        );
+    }
+
     #[test]
     fn mods_iter() {
         let mut iter = GameMods::default().iter();
@@ -0,0 +1,433 @@
+use super::{
+    beatmap::BeatmapCompact, score_::ScoreStatistics, serde_, user_::UserCompact, Cursor, GameMode,
+    GameMods, Grade,
+};
+use crate::{request::GetMultiplayerScores, Osu, OsuResult};
+
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    Deserialize,
+};
+use std::fmt;
+use time::OffsetDateTime;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A score set on a playlist item of a multiplayer room
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct MultiplayerScore {
+    /// Accuracy between `0.0` and `100.0`
+    #[serde(with = "serde_::adjust_acc")]
+    pub accuracy: f32,
+    /// When the score was submitted
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub ended_at: OffsetDateTime,
+    /// The grade of the score
+    #[serde(rename = "rank")]
+    pub grade: Grade,
+    /// The id of the map that was played
+    #[serde(rename = "beatmap_id")]
+    pub map_id: u32,
+    /// The highest combo of the score
+    pub max_combo: u32,
+    /// The mods that were enabled
+    pub mods: GameMods,
+    /// Whether the user passed the map
+    pub passed: bool,
+    /// The id of the playlist item the score was set on
+    #[serde(rename = "playlist_item_id")]
+    pub playlist_id: u32,
+    /// The position of the score within the playlist item's leaderboard
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    /// Performance points of the score, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pp: Option<f32>,
+    /// The id of the room the score was set in
+    pub room_id: u32,
+    /// The total score
+    #[serde(rename = "total_score")]
+    pub score: u32,
+    /// The id of the score
+    #[serde(rename = "id")]
+    pub score_id: u64,
+    /// The hit counts of the score
+    pub statistics: ScoreStatistics,
+    /// The user who set the score
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserCompact>,
+    /// The id of the user who set the score
+    pub user_id: u32,
+}
+
+/// Scores on a playlist item of a multiplayer room
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct MultiplayerScores {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor: Option<Cursor>,
+    /// The parameters that the scores were requested with
+    pub params: MultiplayerScoresParams,
+    #[serde(default)]
+    pub(crate) playlist_id: u32,
+    #[serde(default)]
+    pub(crate) room_id: u32,
+    /// The scores of the current page
+    pub scores: Vec<MultiplayerScore>,
+    /// Total amount of scores on the playlist item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    /// The score of the authenticated user, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<MultiplayerScore>,
+}
+
+impl MultiplayerScores {
+    /// Returns whether there is a next page of scores,
+    /// retrievable via [`get_next`](MultiplayerScores::get_next).
+    #[inline]
+    pub fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// If [`has_more`](MultiplayerScores::has_more) is true, the API can provide the next set of scores and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<MultiplayerScores>> {
+        let cursor = self.cursor.clone()?;
+
+        // The cursor is tied to the score order it was created for
+        let req = osu
+            .multiplayer_scores(self.room_id, self.playlist_id)
+            .limit(self.params.limit)
+            .sort(self.params.sort.clone())
+            .cursor(cursor);
+
+        Some(req.await)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct MultiplayerScoresParams {
+    /// Maximum amount of scores per page
+    pub limit: u32,
+    /// The order of the scores
+    pub sort: MultiplayerScoresSort,
+}
+
+/// A multiplayer room including its playlist
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Room {
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub ends_at: Option<OffsetDateTime>,
+    pub host: UserCompact,
+    pub name: String,
+    /// Amount of users that have participated in the room
+    #[serde(default)]
+    pub participant_count: u32,
+    /// The playlist items of the room.
+    ///
+    /// For rooms of [`GetRooms`](crate::request::GetRooms), this only contains the current item.
+    #[serde(default)]
+    pub playlist: Vec<PlaylistItem>,
+    #[serde(rename = "id")]
+    pub room_id: u32,
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub starts_at: OffsetDateTime,
+    /// The id of the room's host
+    pub user_id: u32,
+}
+
+impl Room {
+    /// Returns the playlist item with the given id, e.g. the
+    /// [`playlist_id`](MultiplayerScore::playlist_id) of a score.
+    #[inline]
+    pub fn playlist_item(&self, playlist_id: u32) -> Option<&PlaylistItem> {
+        self.playlist
+            .iter()
+            .find(|item| item.playlist_id == playlist_id)
+    }
+}
+
+/// The kind of multiplayer rooms
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RoomMode {
+    /// Rooms that are played asynchronously over a longer period of time
+    Playlists,
+    /// Realtime multiplayer lobbies
+    Realtime,
+}
+
+impl fmt::Display for RoomMode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self {
+            Self::Playlists => "playlists",
+            Self::Realtime => "realtime",
+        };
+
+        f.write_str(mode)
+    }
+}
+
+/// Available filters for the status of multiplayer rooms
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RoomStatus {
+    /// Rooms that have not yet ended
+    Active,
+    /// Both active and ended rooms
+    All,
+    /// Rooms that have ended
+    Ended,
+}
+
+impl fmt::Display for RoomStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Active => "active",
+            Self::All => "all",
+            Self::Ended => "ended",
+        };
+
+        f.write_str(status)
+    }
+}
+
+/// An item on the playlist of a multiplayer room i.e. a beatmap with its mods
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct PlaylistItem {
+    /// Mods that players may choose to enable
+    pub allowed_mods: GameMods,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beatmap: Option<BeatmapCompact>,
+    #[serde(rename = "beatmap_id")]
+    pub map_id: u32,
+    #[serde(rename = "ruleset_id")]
+    pub mode: GameMode,
+    #[serde(rename = "id")]
+    pub playlist_id: u32,
+    /// Mods that are enabled for all players
+    pub required_mods: GameMods,
+    pub room_id: u32,
+}
+
+impl PlaylistItem {
+    /// Request the [`MultiplayerScores`] of this playlist item.
+    #[inline]
+    pub fn scores<'o>(&self, osu: &'o Osu) -> GetMultiplayerScores<'o> {
+        osu.multiplayer_scores(self.room_id, self.playlist_id)
+    }
+}
+
+/// Available orders for scores of a multiplayer playlist item.
+///
+/// These are the only sort values the API documents for the endpoint;
+/// there is no ordering by submission time. Other values that the API
+/// echoes back in [`MultiplayerScoresParams`] are kept as `Other`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub enum MultiplayerScoresSort {
+    /// Sort by score, lowest first
+    ScoreAscending,
+    /// Sort by score, highest first
+    ScoreDescending,
+    /// A sort value that is not (yet) known to rosu-v2
+    Other(String),
+}
+
+impl MultiplayerScoresSort {
+    /// Returns the sort as it's used by the API
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ScoreAscending => "score_asc",
+            Self::ScoreDescending => "score_desc",
+            Self::Other(other) => other,
+        }
+    }
+}
+
+impl fmt::Display for MultiplayerScoresSort {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+struct MultiplayerScoresSortVisitor;
+
+impl<'de> Visitor<'de> for MultiplayerScoresSortVisitor {
+    type Value = MultiplayerScoresSort;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let sort = match v {
+            "score_asc" => MultiplayerScoresSort::ScoreAscending,
+            "score_desc" => MultiplayerScoresSort::ScoreDescending,
+            _ => MultiplayerScoresSort::Other(v.to_owned()),
+        };
+
+        Ok(sort)
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiplayerScoresSort {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_str(MultiplayerScoresSortVisitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for MultiplayerScoresSort {
+    #[inline]
+    fn serialize<S: serde::ser::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_playlist_item() {
+        let json = r#"{
+            "id": 456,
+            "room_id": 123,
+            "beatmap_id": 789,
+            "ruleset_id": 1,
+            "allowed_mods": [{ "acronym": "HD", "settings": {} }],
+            "required_mods": [{ "acronym": "DT", "settings": {} }, { "acronym": "NF", "settings": {} }]
+        }"#;
+
+        let item: PlaylistItem = serde_json::from_str(json).unwrap();
+
+        assert_eq!(item.playlist_id, 456);
+        assert_eq!(item.map_id, 789);
+        assert_eq!(item.mode, GameMode::Taiko);
+        assert_eq!(item.allowed_mods, GameMods::Hidden);
+        assert_eq!(item.required_mods, GameMods::DoubleTime | GameMods::NoFail);
+        assert!(item.beatmap.is_none());
+    }
+
+    #[test]
+    fn deserialize_rooms() {
+        let json = r#"[
+            {
+                "id": 123,
+                "name": "weekly playlist",
+                "category": "spotlight",
+                "starts_at": "2022-01-01T00:00:00+00:00",
+                "ends_at": "2022-01-08T00:00:00+00:00",
+                "participant_count": 42,
+                "user_id": 2,
+                "host": {
+                    "avatar_url": "",
+                    "country_code": "AU",
+                    "default_group": "default",
+                    "id": 2,
+                    "is_active": true,
+                    "is_bot": false,
+                    "is_deleted": false,
+                    "is_online": false,
+                    "is_supporter": true,
+                    "pm_friends_only": false,
+                    "username": "peppy"
+                },
+                "playlist": [{
+                    "id": 456,
+                    "room_id": 123,
+                    "beatmap_id": 789,
+                    "ruleset_id": 0,
+                    "allowed_mods": [],
+                    "required_mods": []
+                }]
+            },
+            {
+                "id": 124,
+                "name": "realtime lobby",
+                "category": "realtime",
+                "starts_at": "2022-01-02T00:00:00+00:00",
+                "ends_at": null,
+                "user_id": 3,
+                "host": {
+                    "avatar_url": "",
+                    "country_code": "BE",
+                    "default_group": "default",
+                    "id": 3,
+                    "is_active": true,
+                    "is_bot": false,
+                    "is_deleted": false,
+                    "is_online": true,
+                    "is_supporter": false,
+                    "pm_friends_only": false,
+                    "username": "host"
+                }
+            }
+        ]"#;
+
+        let rooms: Vec<Room> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(rooms.len(), 2);
+
+        let room = &rooms[0];
+        assert_eq!(room.room_id, 123);
+        assert_eq!(room.category, "spotlight");
+        assert_eq!(room.host.username.as_str(), "peppy");
+        assert_eq!(room.host.user_id, room.user_id);
+        assert_eq!(room.participant_count, 42);
+        assert!(room.ends_at.is_some());
+        assert_eq!(room.starts_at.unix_timestamp(), 1_640_995_200);
+        assert_eq!(room.playlist_item(456).map(|item| item.map_id), Some(789));
+
+        let room = &rooms[1];
+        assert_eq!(room.category, "realtime");
+        assert_eq!(room.host.user_id, 3);
+        assert_eq!(room.ends_at, None);
+        assert_eq!(room.participant_count, 0);
+        assert!(room.playlist.is_empty());
+    }
+
+    #[test]
+    fn deserialize_scores_params() {
+        let json = r#"{"limit":50,"sort":"score_asc"}"#;
+        let params: MultiplayerScoresParams = serde_json::from_str(json).unwrap();
+
+        assert_eq!(params.limit, 50);
+        assert_eq!(params.sort, MultiplayerScoresSort::ScoreAscending);
+
+        let json = r#"{"limit":50,"sort":"id_desc"}"#;
+        let params: MultiplayerScoresParams = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            params.sort,
+            MultiplayerScoresSort::Other("id_desc".to_owned())
+        );
+        assert_eq!(params.sort.to_string(), "id_desc");
+    }
+}
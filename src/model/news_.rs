@@ -1,7 +1,9 @@
 use super::{serde_, Cursor};
 use crate::{prelude::Username, Osu, OsuResult};
 
+use futures::stream::{self, Stream};
 use serde::Deserialize;
+use std::{cmp::Ordering, collections::HashSet, mem};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
@@ -14,11 +16,15 @@ use time::OffsetDateTime;
 pub struct News {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) cursor: Option<Cursor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor_string: Option<Cursor>,
     #[serde(rename = "news_posts")]
     pub posts: Vec<NewsPost>,
     pub search: NewsSearch,
     #[serde(rename = "news_sidebar")]
     pub sidebar: NewsSidebar,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) year: Option<u32>,
 }
 
 impl News {
@@ -26,20 +32,75 @@ impl News {
     /// retrievable via [`get_next`](News::get_next).
     #[inline]
     pub fn has_more(&self) -> bool {
-        self.cursor.is_some()
+        self.cursor.is_some() || self.cursor_string.is_some()
+    }
+
+    /// The cursor to the next page of news, if any.
+    ///
+    /// The cursor can be stored and later be passed to
+    /// [`GetNews::cursor`](crate::request::GetNews::cursor) to resume from this page.
+    #[inline]
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref().or(self.cursor_string.as_ref())
     }
 
     /// If [`has_more`](News::has_more) is true, the API can provide the next set of news and this method will request them.
     /// Otherwise, this method returns `None`.
     #[inline]
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<News>> {
-        Some(osu.news().cursor(self.cursor.clone()?).await)
+        let cursor = self.cursor()?;
+        let mut req = osu.news().cursor(cursor.clone());
+
+        if let Some(year) = self.year {
+            req = req.year(year);
+        }
+
+        Some(req.await)
+    }
+
+    /// Turn the news into a [`Stream`] that yields all posts of this page
+    /// and then keeps following the cursor until there are no posts left.
+    ///
+    /// The next pages are requested lazily, i.e. only once all posts of the
+    /// current page have been yielded. Posts that were already yielded
+    /// e.g. because they also appeared on a previous page are skipped.
+    /// If a request fails, the error is yielded and the stream ends.
+    pub fn into_stream(mut self, osu: &Osu) -> impl Stream<Item = OsuResult<NewsPost>> + '_ {
+        let posts = mem::take(&mut self.posts).into_iter();
+        let seen = HashSet::new();
+
+        stream::unfold(Some((posts, seen, self)), move |state| async move {
+            let (mut posts, mut seen, mut news) = state?;
+
+            loop {
+                for post in posts.by_ref() {
+                    if seen.insert(post.post_id) {
+                        return Some((Ok(post), Some((posts, seen, news))));
+                    }
+                }
+
+                match news.get_next(osu).await? {
+                    Ok(mut next) => {
+                        posts = mem::take(&mut next.posts).into_iter();
+                        news = next;
+                    }
+                    Err(err) => return Some((Err(err), None)),
+                }
+            }
+        })
     }
 }
 
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(bound(
+        serialize = "__S: rkyv::ser::Serializer + Sized",
+        deserialize = "__D: Sized"
+    ))
+)]
 pub struct NewsPost {
     #[serde(rename = "id")]
     pub post_id: u32,
@@ -65,6 +126,54 @@ pub struct NewsPost {
     /// First paragraph of `content` with HTML markup stripped.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview: Option<String>,
+    /// Full HTML content of the post.
+    /// Only available when requesting a single post through [`Osu::news_post`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The neighbouring posts.
+    /// Only available when requesting a single post through [`Osu::news_post`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds)]
+    pub navigation: Option<NewsNavigation>,
+    /// All fields of the API response that are not modeled (yet).
+    ///
+    /// Only available with the `raw-extra` feature. The captured keys are **unstable**,
+    /// i.e. once a key is modeled as a proper field in a later release,
+    /// it will no longer be part of this value.
+    #[cfg(feature = "raw-extra")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::JsonValueWrapper))]
+    pub extra: serde_json::Value,
+}
+
+impl NewsPost {
+    /// Link to the news post on the osu! website, i.e. `https://osu.ppy.sh/home/news/{slug}`
+    #[inline]
+    pub fn url(&self) -> String {
+        format!("https://osu.ppy.sh/home/news/{}", self.slug)
+    }
+}
+
+/// The previous and next post of a [`NewsPost`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(bound(
+        serialize = "__S: rkyv::ser::Serializer + Sized",
+        deserialize = "__D: Sized"
+    ))
+)]
+pub struct NewsNavigation {
+    /// The next post, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds)]
+    pub newer: Option<Box<NewsPost>>,
+    /// The previous post, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds)]
+    pub older: Option<Box<NewsPost>>,
 }
 
 impl PartialEq for NewsPost {
@@ -76,6 +185,29 @@ impl PartialEq for NewsPost {
 
 impl Eq for NewsPost {}
 
+/// News posts are ordered by their `published_at` date in **descending** order,
+/// i.e. newer posts come first, the same way they're listed on the website.
+///
+/// Posts that were published at the same time are ordered by descending
+/// `post_id` and then by descending `updated_at`.
+impl Ord for NewsPost {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .published_at
+            .cmp(&self.published_at)
+            .then_with(|| other.post_id.cmp(&self.post_id))
+            .then_with(|| other.updated_at.cmp(&self.updated_at))
+    }
+}
+
+impl PartialOrd for NewsPost {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 // TODO
@@ -95,3 +227,53 @@ pub struct NewsSidebar {
     pub posts: Vec<NewsPost>,
     pub years: Vec<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn news_post(post_id: u32, published_at: OffsetDateTime) -> NewsPost {
+        NewsPost {
+            post_id,
+            author: "Walavouchey".into(),
+            edit_url: "https://github.com/ppy/osu-wiki/tree/master/news/2021/2021-08-06-project-loved-august-2021.md".to_owned(),
+            first_image: "https://osu.ppy.sh/wiki/shared/news/banners/project-loved-2.jpg".to_owned(),
+            published_at,
+            updated_at: None,
+            slug: "2021-08-06-project-loved-august-2021".to_owned(),
+            title: "Project Loved: August 2021".to_owned(),
+            preview: None,
+            content: None,
+            navigation: None,
+            #[cfg(feature = "raw-extra")]
+            extra: serde_json::Value::Object(Default::default()),
+        }
+    }
+
+    #[test]
+    fn news_post_url() {
+        let post = news_post(1000, OffsetDateTime::UNIX_EPOCH);
+
+        assert_eq!(
+            post.url(),
+            "https://osu.ppy.sh/home/news/2021-08-06-project-loved-august-2021"
+        );
+    }
+
+    #[test]
+    fn news_post_newest_first() {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+
+        let mut posts = [
+            news_post(1, epoch),
+            news_post(3, epoch + time::Duration::days(2)),
+            news_post(2, epoch),
+            news_post(4, epoch + time::Duration::days(1)),
+        ];
+
+        posts.sort();
+
+        let ids: Vec<_> = posts.iter().map(|post| post.post_id).collect();
+        assert_eq!(ids, [3, 4, 2, 1]);
+    }
+}
@@ -6,16 +6,35 @@ use super::{
 };
 use crate::{model::user_::CountryCode, Osu, OsuResult};
 
+use futures::stream::{self, Stream};
 use serde::{
     de::{Deserializer, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
     Deserialize,
 };
-use std::fmt;
-use time::OffsetDateTime;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+};
+use time::{Duration, OffsetDateTime};
+
+#[cfg(not(feature = "rkyv"))]
+use super::Cursor;
+
+#[cfg(feature = "serialize")]
+use super::user_::UserKudosu;
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 
+/// The charts of a spotlight.
+///
+/// Unlike [`Rankings`] and [`CountryRankings`], charts are not paginated.
+/// The API provides all ranked users at once so there is no `get_next` method.
+///
+/// Note that the `PartialEq` implementation compares the spotlight only
+/// through [`Spotlight`]'s `PartialEq` implementation, i.e. only through its
+/// id and dates.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -33,7 +52,7 @@ pub struct ChartRankings {
     pub spotlight: Spotlight,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct CountryRanking {
@@ -55,10 +74,64 @@ pub struct CountryRanking {
     pub ranked_score: u64,
 }
 
+impl CountryRanking {
+    /// Average performance points per active user, or `0.0` if there are no active users.
+    #[inline]
+    pub fn average_pp(&self) -> f32 {
+        if self.active_users == 0 {
+            0.0
+        } else {
+            self.pp / self.active_users as f32
+        }
+    }
+
+    /// Average playcount per active user, or `0.0` if there are no active users.
+    #[inline]
+    pub fn average_playcount(&self) -> f64 {
+        if self.active_users == 0 {
+            0.0
+        } else {
+            self.playcount as f64 / self.active_users as f64
+        }
+    }
+
+    /// Average ranked score per active user, or `0.0` if there are no active users.
+    #[inline]
+    pub fn average_ranked_score(&self) -> f64 {
+        if self.active_users == 0 {
+            0.0
+        } else {
+            self.ranked_score as f64 / self.active_users as f64
+        }
+    }
+}
+
+/// Country rankings are considered equal if their country code matches.
+///
+/// Other fields such as `pp` or `active_users` are intentionally not compared
+/// so that rankings of the same country from different requests can be used as keys.
+impl PartialEq for CountryRanking {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.country_code == other.country_code
+    }
+}
+
+impl Eq for CountryRanking {}
+
+impl Hash for CountryRanking {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.country_code.hash(state);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct CountryRankings {
+    #[serde(default)]
+    pub(crate) mode: Option<GameMode>,
     /// The next page of the ranking
     #[serde(
         default,
@@ -67,6 +140,9 @@ pub struct CountryRankings {
         skip_serializing_if = "Option::is_none"
     )]
     pub next_page: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) cursor_string: Option<Cursor>,
     /// Country details ordered by pp in descending order.
     pub ranking: Vec<CountryRanking>,
     /// Total amount of countries
@@ -74,11 +150,50 @@ pub struct CountryRankings {
 }
 
 impl CountryRankings {
-    /// If `next_page` is `Some`, the API can provide the next set of countries and this method will request them.
-    /// Otherwise, this method returns `None`.
+    /// If `next_page` or the API's cursor string is available, the API can provide the next set
+    /// of countries and this method will request them. Otherwise, this method returns `None`.
     #[inline]
-    pub async fn get_next(&self, osu: &Osu, mode: GameMode) -> Option<OsuResult<CountryRankings>> {
-        Some(osu.country_rankings(mode).page(self.next_page?).await)
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<CountryRankings>> {
+        let mode = self.mode?;
+        let req = osu.country_rankings(mode);
+
+        let req = match self.next_page {
+            Some(page) => req.page(page),
+            #[cfg(not(feature = "rkyv"))]
+            None => req.cursor(self.cursor_string.clone()?),
+            #[cfg(feature = "rkyv")]
+            None => return None,
+        };
+
+        Some(req.await)
+    }
+
+    /// Turn the rankings into a [`Stream`] that yields all countries of this
+    /// page and then keeps requesting the next pages until there are none left.
+    ///
+    /// The next pages are requested lazily, i.e. only once all countries of
+    /// the current page have been yielded. If a request fails, the error
+    /// is yielded and the stream ends.
+    pub fn into_stream(mut self, osu: &Osu) -> impl Stream<Item = OsuResult<CountryRanking>> + '_ {
+        let countries = mem::take(&mut self.ranking).into_iter();
+
+        stream::unfold(Some((countries, self)), move |state| async move {
+            let (mut countries, mut rankings) = state?;
+
+            loop {
+                if let Some(country) = countries.next() {
+                    return Some((Ok(country), Some((countries, rankings))));
+                }
+
+                match rankings.get_next(osu).await? {
+                    Ok(mut next) => {
+                        countries = mem::take(&mut next.ranking).into_iter();
+                        rankings = next;
+                    }
+                    Err(err) => return Some((Err(err), None)),
+                }
+            }
+        })
     }
 }
 
@@ -86,6 +201,9 @@ impl CountryRankings {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct Rankings {
+    #[serde(default)]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) country: Option<CountryCode>,
     #[serde(default)]
     pub(crate) mode: Option<GameMode>,
     #[serde(
@@ -95,6 +213,9 @@ pub struct Rankings {
         skip_serializing_if = "Option::is_none"
     )]
     pub next_page: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) cursor_string: Option<Cursor>,
     #[serde(
         deserialize_with = "deserialize_user_stats_vec",
         serialize_with = "serialize_user_stats_vec"
@@ -104,6 +225,9 @@ pub struct Rankings {
     #[cfg(not(feature = "rkyv"))]
     pub(crate) ranking_type: Option<RankingType>,
     pub total: u32,
+    #[serde(default)]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) variant: Option<ManiaVariant>,
 }
 
 struct UserStatsVecVisitor;
@@ -172,18 +296,21 @@ impl<'de> Visitor<'de> for UserStatsVisitor {
                 "grade_counts" => grade_counts = Some(map.next_value()?),
                 "is_ranked" => is_ranked = Some(map.next_value()?),
                 "level" => level = Some(map.next_value()?),
-                "maximum_combo" => max_combo = Some(map.next_value()?),
+                "maximum_combo" => max_combo = map.next_value()?,
                 "play_count" => playcount = Some(map.next_value()?),
                 "play_time" => {
                     playtime = Some(map.next_value::<Option<u32>>()?.unwrap_or_default())
                 }
-                "pp" => pp = Some(map.next_value::<Option<f32>>()?.unwrap_or_default()),
+                "pp" => pp = map.next_value()?,
                 "ranked_score" => ranked_score = Some(map.next_value()?),
-                "replays_watched_by_others" => replays_watched = Some(map.next_value()?),
+                "replays_watched_by_others" => replays_watched = map.next_value()?,
                 "total_hits" => total_hits = Some(map.next_value()?),
                 "total_score" => total_score = Some(map.next_value()?),
                 "user" => user = map.next_value()?,
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("UserStatistics", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
@@ -193,13 +320,9 @@ impl<'de> Visitor<'de> for UserStatsVisitor {
         let grade_counts = grade_counts.ok_or_else(|| Error::missing_field("grade_counts"))?;
         let is_ranked = is_ranked.ok_or_else(|| Error::missing_field("is_ranked"))?;
         let level = level.ok_or_else(|| Error::missing_field("level"))?;
-        let max_combo = max_combo.ok_or_else(|| Error::missing_field("maximum_combo"))?;
         let playcount = playcount.ok_or_else(|| Error::missing_field("play_count"))?;
         let playtime = playtime.ok_or_else(|| Error::missing_field("play_time"))?;
-        let pp = pp.ok_or_else(|| Error::missing_field("pp"))?;
         let ranked_score = ranked_score.ok_or_else(|| Error::missing_field("ranked_score"))?;
-        let replays_watched =
-            replays_watched.ok_or_else(|| Error::missing_field("replays_watched_by_others"))?;
         let total_hits = total_hits.ok_or_else(|| Error::missing_field("total_hits"))?;
         let total_score = total_score.ok_or_else(|| Error::missing_field("total_score"))?;
         let mut user: UserCompact = user.ok_or_else(|| Error::missing_field("user"))?;
@@ -211,12 +334,12 @@ impl<'de> Visitor<'de> for UserStatsVisitor {
             grade_counts,
             is_ranked,
             level,
-            max_combo,
+            max_combo: max_combo.unwrap_or_default(),
             playcount,
             playtime,
             pp,
             ranked_score,
-            replays_watched,
+            replays_watched: replays_watched.unwrap_or_default(),
             total_hits,
             total_score,
         };
@@ -350,6 +473,8 @@ struct UserCompactWithoutStats<'u> {
     pub is_nat: &'u Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_silenced: &'u Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kudosu: &'u Option<UserKudosu>,
     #[serde(
         rename = "loved_beatmapset_count",
         skip_serializing_if = "Option::is_none"
@@ -385,6 +510,9 @@ struct UserCompactWithoutStats<'u> {
         skip_serializing_if = "Option::is_none"
     )]
     pub pending_mapset_count: &'u Option<u32>,
+    #[cfg(feature = "raw-extra")]
+    #[serde(flatten)]
+    pub extra: &'u serde_json::Value,
 }
 
 #[cfg(feature = "serialize")]
@@ -423,6 +551,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             is_moderator,
             is_nat,
             is_silenced,
+            kudosu,
             loved_mapset_count,
             medals,
             monthly_playcounts,
@@ -435,8 +564,11 @@ impl<'u> UserCompactWithoutStats<'u> {
             scores_first_count,
             scores_recent_count,
             statistics: _,
+            statistics_rulesets: _,
             support_level,
             pending_mapset_count,
+            #[cfg(feature = "raw-extra")]
+            extra,
         } = user;
 
         Self {
@@ -472,6 +604,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             is_moderator,
             is_nat,
             is_silenced,
+            kudosu,
             loved_mapset_count,
             medals,
             monthly_playcounts,
@@ -485,6 +618,8 @@ impl<'u> UserCompactWithoutStats<'u> {
             scores_recent_count,
             support_level,
             pending_mapset_count,
+            #[cfg(feature = "raw-extra")]
+            extra,
         }
     }
 }
@@ -516,6 +651,30 @@ pub(crate) enum RankingType {
     Score,
 }
 
+/// The keymode variant of osu!mania rankings
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum ManiaVariant {
+    /// Only consider 4K scores
+    #[serde(rename = "4k")]
+    FourKeys,
+    /// Only consider 7K scores
+    #[serde(rename = "7k")]
+    SevenKeys,
+}
+
+impl fmt::Display for ManiaVariant {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match self {
+            Self::FourKeys => "4k",
+            Self::SevenKeys => "7k",
+        };
+
+        f.write_str(variant)
+    }
+}
+
 impl fmt::Display for RankingType {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -531,23 +690,84 @@ impl fmt::Display for RankingType {
 }
 
 impl Rankings {
-    /// If `next_page` is `Some`, the API can provide the next set of users and this method will request them.
-    /// Otherwise, this method returns `None`.
+    /// Compare only the content of the rankings, i.e. the `ranking` and `total`.
+    ///
+    /// Unlike the `PartialEq` implementation, this ignores which
+    /// request produced the rankings and the cursor to the next page.
+    #[inline]
+    pub fn content_eq(&self, other: &Self) -> bool {
+        self.total == other.total && self.ranking == other.ranking
+    }
+
+    /// If `next_page` or the API's cursor string is available, the API can provide the next set
+    /// of users and this method will request them. Otherwise, this method returns `None`.
     #[inline]
     #[cfg(not(feature = "rkyv"))]
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Rankings>> {
-        let page = self.next_page?;
         let mode = self.mode?;
         let kind = self.ranking_type?;
 
         let rankings = match kind {
-            RankingType::Performance => osu.performance_rankings(mode).page(page).await,
-            RankingType::Score => osu.score_rankings(mode).page(page).await,
+            RankingType::Performance => {
+                let mut req = match self.next_page {
+                    Some(page) => osu.performance_rankings(mode).page(page),
+                    None => osu
+                        .performance_rankings(mode)
+                        .cursor(self.cursor_string.clone()?),
+                };
+
+                if let Some(ref country) = self.country {
+                    req = req.country(country.clone());
+                }
+
+                if let Some(variant) = self.variant {
+                    req = req.variant(variant);
+                }
+
+                req.await
+            }
+            RankingType::Score => {
+                let req = match self.next_page {
+                    Some(page) => osu.score_rankings(mode).page(page),
+                    None => osu.score_rankings(mode).cursor(self.cursor_string.clone()?),
+                };
+
+                req.await
+            }
             RankingType::Charts | RankingType::Country => unreachable!(),
         };
 
         Some(rankings)
     }
+
+    /// Turn the rankings into a [`Stream`] that yields all users of this
+    /// page and then keeps requesting the next pages until there are none left.
+    ///
+    /// The next pages are requested lazily, i.e. only once all users of
+    /// the current page have been yielded. If a request fails, the error
+    /// is yielded and the stream ends.
+    #[cfg(not(feature = "rkyv"))]
+    pub fn into_stream(mut self, osu: &Osu) -> impl Stream<Item = OsuResult<UserCompact>> + '_ {
+        let users = mem::take(&mut self.ranking).into_iter();
+
+        stream::unfold(Some((users, self)), move |state| async move {
+            let (mut users, mut rankings) = state?;
+
+            loop {
+                if let Some(user) = users.next() {
+                    return Some((Ok(user), Some((users, rankings))));
+                }
+
+                match rankings.get_next(osu).await? {
+                    Ok(mut next) => {
+                        users = mem::take(&mut next.ranking).into_iter();
+                        rankings = next;
+                    }
+                    Err(err) => return Some((Err(err), None)),
+                }
+            }
+        })
+    }
 }
 
 struct RankingsCursorVisitor;
@@ -557,7 +777,7 @@ impl<'de> Visitor<'de> for RankingsCursorVisitor {
 
     #[inline]
     fn expecting(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("a u32, a map containing a `page` field, or null")
+        f.write_str("a u32, a string, a map containing a `page` field, or null")
     }
 
     #[inline]
@@ -565,6 +785,12 @@ impl<'de> Visitor<'de> for RankingsCursorVisitor {
         Ok(Some(v as u32))
     }
 
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        // An opaque cursor string cannot be followed through `next_page`;
+        // it is provided through its own `cursor_string` field instead
+        Ok(v.parse().ok())
+    }
+
     #[inline]
     fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
         d.deserialize_any(Self)
@@ -584,12 +810,17 @@ impl<'de> Visitor<'de> for RankingsCursorVisitor {
                     page.replace(map.next_value()?);
                 }
                 _ => {
+                    #[cfg(feature = "log-unknown-fields")]
+                    serde_::log_unknown_field("rankings cursor", key);
+
                     let _: IgnoredAny = map.next_value()?;
                 }
             }
         }
 
-        page.ok_or_else(|| Error::missing_field("page")).map(Some)
+        // Cursors without page cannot be followed through `next_page` but
+        // should not fail the deserialization; `cursor_string` may still be available
+        Ok(page)
     }
 }
 
@@ -611,6 +842,8 @@ pub struct Spotlight {
     /// The name of the spotlight.
     pub name: String,
     /// The number of users participating in this spotlight. This is only shown when viewing a single spotlight.
+    ///
+    /// Lists of spotlights may still contain it, possibly as `null`, in which case it's `None`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub participant_count: Option<u32>,
     /// The ID of this spotlight.
@@ -618,13 +851,112 @@ pub struct Spotlight {
     pub spotlight_id: u32,
     /// The type of spotlight.
     #[serde(rename = "type")]
-    pub spotlight_type: String,
+    pub spotlight_type: SpotlightType,
     /// The starting date of the spotlight.
     #[serde(with = "serde_::datetime")]
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
     pub start_date: OffsetDateTime,
 }
 
+impl Spotlight {
+    /// Returns whether the spotlight is currently running.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.is_active_at(OffsetDateTime::now_utc())
+    }
+
+    /// Returns whether the spotlight was or will be running at the given time.
+    #[inline]
+    pub fn is_active_at(&self, datetime: OffsetDateTime) -> bool {
+        self.start_date <= datetime && datetime < self.end_date
+    }
+
+    /// The time between the start and end date of the spotlight.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.end_date - self.start_date
+    }
+}
+
+/// The type of a [`Spotlight`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub enum SpotlightType {
+    /// The yearly "Best of" spotlight featuring maps of the past year
+    BestOf,
+    /// A spotlight featuring maps of the past month
+    Monthly,
+    /// A spotlight for a special occasion, e.g. an anniversary
+    Special,
+    /// A spotlight whose maps share a common theme
+    Theme,
+    /// A type that is not (yet) known to rosu-v2
+    Other(String),
+}
+
+impl SpotlightType {
+    /// Returns the type as it's used by the API
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::BestOf => "bestof",
+            Self::Monthly => "monthly",
+            Self::Special => "special",
+            Self::Theme => "theme",
+            Self::Other(other) => other,
+        }
+    }
+}
+
+impl fmt::Display for SpotlightType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+struct SpotlightTypeVisitor;
+
+impl<'de> Visitor<'de> for SpotlightTypeVisitor {
+    type Value = SpotlightType;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let kind = match v {
+            "bestof" => SpotlightType::BestOf,
+            "monthly" => SpotlightType::Monthly,
+            "special" => SpotlightType::Special,
+            "theme" => SpotlightType::Theme,
+            _ => SpotlightType::Other(v.to_owned()),
+        };
+
+        Ok(kind)
+    }
+}
+
+impl<'de> Deserialize<'de> for SpotlightType {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_str(SpotlightTypeVisitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for SpotlightType {
+    #[inline]
+    fn serialize<S: serde::ser::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// Spotlights are considered equal if their id, start date, and end date match.
+///
+/// Other fields such as `name` or `participant_count` are intentionally not compared
+/// since they don't identify the spotlight and may differ between endpoints.
 impl PartialEq for Spotlight {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -635,3 +967,226 @@ impl PartialEq for Spotlight {
 }
 
 impl Eq for Spotlight {}
+
+impl Hash for Spotlight {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.spotlight_id.hash(state);
+        self.start_date.hash(state);
+        self.end_date.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spotlight() -> Spotlight {
+        Spotlight {
+            end_date: OffsetDateTime::from_unix_timestamp(1_625_097_600).unwrap(),
+            mode_specific: true,
+            name: "Summer Spotlight 2021".to_owned(),
+            participant_count: None,
+            spotlight_id: 250,
+            spotlight_type: SpotlightType::Other("seasonal".to_owned()),
+            start_date: OffsetDateTime::from_unix_timestamp(1_622_505_600).unwrap(),
+        }
+    }
+
+    fn country_ranking(active_users: u32) -> CountryRanking {
+        CountryRanking {
+            active_users,
+            country: "Germany".to_owned(),
+            country_code: "DE".into(),
+            playcount: 1_000,
+            pp: 500.0,
+            ranked_score: 2_000_000,
+        }
+    }
+
+    #[test]
+    fn country_ranking_averages() {
+        let ranking = country_ranking(4);
+
+        assert!((ranking.average_pp() - 125.0).abs() < f32::EPSILON);
+        assert!((ranking.average_playcount() - 250.0).abs() < f64::EPSILON);
+        assert!((ranking.average_ranked_score() - 500_000.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn country_ranking_keyed_by_country_code() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(country_ranking(4));
+
+        assert!(!set.insert(country_ranking(5)));
+
+        let mut other = country_ranking(4);
+        other.country_code = "BE".into();
+
+        assert!(set.insert(other));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn country_ranking_averages_without_active_users() {
+        let ranking = country_ranking(0);
+
+        assert_eq!(ranking.average_pp(), 0.0);
+        assert_eq!(ranking.average_playcount(), 0.0);
+        assert_eq!(ranking.average_ranked_score(), 0.0);
+    }
+
+    #[test]
+    fn spotlight_duration() {
+        assert_eq!(spotlight().duration(), Duration::days(30));
+    }
+
+    #[test]
+    fn spotlight_active() {
+        let spotlight = spotlight();
+
+        assert!(spotlight.is_active_at(spotlight.start_date));
+        assert!(spotlight.is_active_at(spotlight.start_date + Duration::days(15)));
+    }
+
+    #[test]
+    fn spotlight_hash_set() {
+        let mut renamed = spotlight();
+        renamed.name = "Renamed".to_owned();
+
+        let spotlights: std::collections::HashSet<_> =
+            vec![spotlight(), renamed].into_iter().collect();
+
+        assert_eq!(spotlights.len(), 1);
+    }
+
+    #[test]
+    fn rankings_from_bytes() {
+        let json = br#"{
+            "cursor": null,
+            "ranking": [{
+                "grade_counts": { "ss": 1, "ssh": 2, "s": 3, "sh": 4, "a": 5 },
+                "hit_accuracy": 98.5,
+                "is_ranked": true,
+                "level": { "current": 100, "progress": 50 },
+                "play_count": 1000,
+                "play_time": 3600,
+                "pp": 12345.6,
+                "ranked_score": 1000000,
+                "total_hits": 10000,
+                "total_score": 2000000,
+                "user": {
+                    "avatar_url": "",
+                    "country_code": "BE",
+                    "default_group": "default",
+                    "id": 2,
+                    "is_active": true,
+                    "is_bot": false,
+                    "is_deleted": false,
+                    "is_online": false,
+                    "is_supporter": false,
+                    "pm_friends_only": false,
+                    "username": "peppy"
+                }
+            }],
+            "total": 1
+        }"#;
+
+        let rankings: Rankings = crate::model::from_bytes(json).unwrap();
+        let user = &rankings.ranking[0];
+        let stats = user.statistics.as_ref().unwrap();
+
+        assert_eq!(user.user_id, 2);
+        assert_eq!(stats.pp, Some(12345.6));
+        assert_eq!(stats.playcount, 1000);
+
+        assert!(crate::model::from_bytes::<Rankings>(b"{}").is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serialize", not(feature = "rkyv")))]
+    fn rankings_variant_roundtrip() {
+        let json = r#"{"cursor":{"page":2},"ranking":[],"total":10000}"#;
+        let mut rankings: Rankings = serde_json::from_str(json).unwrap();
+
+        assert_eq!(rankings.next_page, Some(2));
+        assert_eq!(rankings.variant, None);
+
+        rankings.mode = Some(GameMode::Mania);
+        rankings.ranking_type = Some(RankingType::Performance);
+        rankings.variant = Some(ManiaVariant::SevenKeys);
+
+        let serialized = serde_json::to_string(&rankings).unwrap();
+        let deserialized: Rankings = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.mode, Some(GameMode::Mania));
+        assert_eq!(deserialized.variant, Some(ManiaVariant::SevenKeys));
+    }
+
+    #[test]
+    #[cfg(not(feature = "rkyv"))]
+    fn rankings_cursor_string() {
+        let json = r#"{"cursor":null,"cursor_string":"eyJwYWdlIjozfQ","ranking":[],"total":10000}"#;
+        let rankings: CountryRankings = serde_json::from_str(json).unwrap();
+
+        assert_eq!(rankings.next_page, None);
+        assert_eq!(
+            rankings.cursor_string,
+            Some(Cursor::new("eyJwYWdlIjozfQ".into()))
+        );
+
+        let json = r#"{"cursor":"3","ranking":[],"total":10000}"#;
+        let rankings: CountryRankings = serde_json::from_str(json).unwrap();
+        assert_eq!(rankings.next_page, Some(3));
+
+        let json = r#"{"cursor":"eyJwYWdlIjozfQ","ranking":[],"total":10000}"#;
+        let rankings: CountryRankings = serde_json::from_str(json).unwrap();
+        assert_eq!(rankings.next_page, None);
+    }
+
+    #[test]
+    fn rankings_cursor_without_page() {
+        let json = r#"{"cursor":{"id":123},"ranking":[],"total":10000}"#;
+        let rankings: CountryRankings = serde_json::from_str(json).unwrap();
+        assert_eq!(rankings.next_page, None);
+    }
+
+    #[test]
+    fn spotlight_participant_count() {
+        let json = |participant_count: &str| {
+            format!(
+                r#"{{
+                    "end_date": "2021-07-01T00:00:00+00:00",
+                    "id": 250,
+                    "mode_specific": true,
+                    "name": "Summer Spotlight 2021",
+                    {}
+                    "start_date": "2021-06-01T00:00:00+00:00",
+                    "type": "seasonal"
+                }}"#,
+                participant_count
+            )
+        };
+
+        let with_count: Spotlight =
+            serde_json::from_str(&json(r#""participant_count": 1234,"#)).unwrap();
+        assert_eq!(with_count.participant_count, Some(1234));
+
+        let with_null: Spotlight =
+            serde_json::from_str(&json(r#""participant_count": null,"#)).unwrap();
+        assert_eq!(with_null.participant_count, None);
+
+        let without: Spotlight = serde_json::from_str(&json("")).unwrap();
+        assert_eq!(without.participant_count, None);
+    }
+
+    #[test]
+    fn spotlight_inactive() {
+        let spotlight = spotlight();
+
+        assert!(!spotlight.is_active_at(spotlight.start_date - Duration::seconds(1)));
+        assert!(!spotlight.is_active_at(spotlight.end_date));
+    }
+}
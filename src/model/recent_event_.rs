@@ -5,8 +5,9 @@ use super::{
     beatmap::RankStatus,
     serde_,
     user_::{Medal, Username},
-    GameMode, Grade,
+    Cursor, GameMode, Grade,
 };
+use crate::{Osu, OsuResult};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
@@ -25,6 +26,48 @@ pub struct RecentEvent {
     pub event_type: EventType,
 }
 
+/// The site-wide feed of recent events, see [`Osu::events`](crate::Osu::events).
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Events {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor: Option<Cursor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor_string: Option<Cursor>,
+    pub events: Vec<RecentEvent>,
+    #[serde(skip)]
+    pub(crate) sort: Option<&'static str>,
+}
+
+impl Events {
+    /// Returns whether there is a next page of events,
+    /// retrievable via [`get_next`](Events::get_next).
+    #[inline]
+    pub fn has_more(&self) -> bool {
+        self.cursor.is_some() || self.cursor_string.is_some()
+    }
+
+    /// If [`has_more`](Events::has_more) is true, the API can provide the next set of events and this method will request them.
+    /// Otherwise, this method returns `None`.
+    ///
+    /// The next set of events is ordered by event id the same way as this one.
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Events>> {
+        let cursor = self.cursor.as_ref().or(self.cursor_string.as_ref())?;
+        let req = osu.events().cursor(cursor.clone());
+
+        // Without a sort, the API's default order is kept
+        let req = match self.sort {
+            Some("id_asc") => req.sort_ascending(),
+            Some(_) => req.sort_descending(),
+            None => req,
+        };
+
+        Some(req.await)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -132,3 +175,75 @@ pub struct EventUser {
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
     pub previous_username: Option<Username>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_rank_event() {
+        let json = r#"{
+            "created_at": "2021-08-06T12:34:56+00:00",
+            "id": 123,
+            "type": "rank",
+            "scoreRank": "SH",
+            "rank": 7,
+            "mode": "osu",
+            "beatmap": { "title": "artist - title [diff]", "url": "/b/1234" },
+            "user": { "username": "user", "url": "/u/1" }
+        }"#;
+
+        let event: RecentEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_id, 123);
+        assert!(matches!(
+            event.event_type,
+            EventType::Rank {
+                grade: Grade::SH,
+                rank: 7,
+                mode: GameMode::Osu,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn deserialize_username_change_event() {
+        let json = r#"{
+            "created_at": "2021-08-06T12:34:56+00:00",
+            "id": 456,
+            "type": "usernameChange",
+            "user": { "username": "new", "url": "/u/1", "previousUsername": "old" }
+        }"#;
+
+        let event: RecentEvent = serde_json::from_str(json).unwrap();
+
+        match event.event_type {
+            EventType::UsernameChange { user } => {
+                assert_eq!(user.username.as_str(), "new");
+                assert_eq!(user.previous_username.as_deref(), Some("old"));
+            }
+            other => panic!("expected username change event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserialize_events_cursor_string() {
+        let json = r#"{
+            "events": [{
+                "created_at": "2021-08-06T12:34:56+00:00",
+                "id": 789,
+                "type": "userSupportFirst",
+                "user": { "username": "user", "url": "/u/1" }
+            }],
+            "cursor": null,
+            "cursor_string": "eyJldmVudF9pZCI6Nzg5fQ"
+        }"#;
+
+        let events: Events = serde_json::from_str(json).unwrap();
+
+        assert_eq!(events.events.len(), 1);
+        assert!(events.cursor.is_none());
+        assert!(events.has_more());
+    }
+}
@@ -150,3 +150,39 @@ impl<D: Fallible> DeserializeWith<ArchivedDateUtc, Date, D> for DateWrapper {
         Ok(Date::from_ordinal_date(year, ordinal).unwrap())
     }
 }
+
+/// Archives a [`Value`](serde_json::Value) as its JSON string.
+#[cfg(feature = "raw-extra")]
+pub struct JsonValueWrapper;
+
+#[cfg(feature = "raw-extra")]
+impl ArchiveWith<serde_json::Value> for JsonValueWrapper {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    unsafe fn resolve_with(
+        field: &serde_json::Value,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        ArchivedString::resolve_from_str(&field.to_string(), pos, resolver, out);
+    }
+}
+
+#[cfg(feature = "raw-extra")]
+impl<S: Fallible + Serializer> SerializeWith<serde_json::Value, S> for JsonValueWrapper {
+    #[inline]
+    fn serialize_with(field: &serde_json::Value, s: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(&field.to_string(), s)
+    }
+}
+
+#[cfg(feature = "raw-extra")]
+impl<D: Fallible> DeserializeWith<ArchivedString, serde_json::Value, D> for JsonValueWrapper {
+    #[inline]
+    fn deserialize_with(field: &ArchivedString, _: &mut D) -> Result<serde_json::Value, D::Error> {
+        Ok(serde_json::from_str(field.as_str()).unwrap())
+    }
+}
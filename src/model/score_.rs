@@ -12,9 +12,15 @@ use serde::Deserialize;
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 use time::OffsetDateTime;
 
-#[derive(Debug, Deserialize)]
-pub(crate) struct BeatmapScores {
-    pub(crate) scores: Vec<Score>,
+/// The score leaderboard of a beatmap
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapScores {
+    pub scores: Vec<Score>,
+    /// The score of the authenticated user, if any
+    #[serde(default, rename = "userScore", skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<BeatmapUserScore>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
@@ -65,6 +71,8 @@ pub struct Score {
     pub mods: GameMods,
     #[serde(alias = "legacy_perfect")]
     pub perfect: bool,
+    /// `None` if the score awards no pp, e.g. on loved or unranked maps,
+    /// as opposed to `Some(0.0)` for a score that awards zero pp
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pp: Option<f32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -82,6 +90,22 @@ pub struct Score {
     pub user_id: u32,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weight: Option<ScoreWeight>,
+    /// The lazer build the score was set on, `None` for osu!stable scores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_id: Option<u32>,
+    /// The id of the score on osu!stable, `None` for lazer scores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_score_id: Option<u64>,
+    /// The pp if the score was a full combo.
+    ///
+    /// Not provided by the API, only filled through [`Score::with_performance`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_fc_pp: Option<f32>,
+    /// The pp of an SS with the score's mods.
+    ///
+    /// Not provided by the API, only filled through [`Score::with_performance`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pp: Option<f32>,
 }
 
 impl Score {
@@ -90,6 +114,92 @@ impl Score {
         osu.user(self.user_id)
     }
 
+    /// Whether the score was set on lazer, based on the presence of a `build_id`.
+    ///
+    /// osu!stable scores, either from the legacy API format or converted
+    /// into the lazer format, don't have a build id.
+    #[inline]
+    pub fn is_lazer(&self) -> bool {
+        self.build_id.is_some()
+    }
+
+    /// Link to the score's beatmap on the osu! website, i.e. `https://osu.ppy.sh/beatmaps/{map_id}`.
+    ///
+    /// `None` if the beatmap is known to be deleted.
+    #[inline]
+    pub fn map_url(&self) -> Option<String> {
+        if self.is_map_deleted() {
+            return None;
+        }
+
+        Some(format!("https://osu.ppy.sh/beatmaps/{}", self.map_id))
+    }
+
+    /// Link to the score's beatmapset on the osu! website, i.e. `https://osu.ppy.sh/beatmapsets/{mapset_id}`.
+    ///
+    /// `None` if the beatmap is known to be deleted or if neither `map` nor `mapset` are available.
+    #[inline]
+    pub fn mapset_url(&self) -> Option<String> {
+        if self.is_map_deleted() {
+            return None;
+        }
+
+        let mapset_id = self
+            .mapset
+            .as_ref()
+            .map(|mapset| mapset.mapset_id)
+            .or_else(|| self.map.as_ref().map(|map| map.mapset_id))?;
+
+        Some(format!("https://osu.ppy.sh/beatmapsets/{mapset_id}"))
+    }
+
+    /// Link to download the score's replay, `None` if the score has no replay.
+    ///
+    /// Scores in the new format, i.e. lazer scores and osu!stable scores that
+    /// have a `legacy_score_id`, use `https://osu.ppy.sh/scores/{id}/download`.
+    /// Scores in the legacy format use `https://osu.ppy.sh/scores/{mode}/{id}/download`.
+    #[inline]
+    pub fn replay_url(&self) -> Option<String> {
+        if self.replay != Some(true) {
+            return None;
+        }
+
+        let url = if self.is_lazer() || self.legacy_score_id.is_some() {
+            format!("https://osu.ppy.sh/scores/{}/download", self.id)
+        } else {
+            format!(
+                "https://osu.ppy.sh/scores/{}/{}/download",
+                self.mode, self.id
+            )
+        };
+
+        Some(url)
+    }
+
+    /// Fill in performance values calculated by the given [`PerformanceProvider`].
+    ///
+    /// `pp` is only replaced if the API did not provide it, e.g. for scores on
+    /// loved or unranked maps, while `if_fc_pp` and `max_pp` are always set to
+    /// the provided values. If the provider returns `None`, the score remains unchanged.
+    pub fn with_performance(mut self, provider: &impl PerformanceProvider) -> Self {
+        if let Some(performance) = provider.performance(&self) {
+            if self.pp.is_none() {
+                self.pp = Some(performance.pp);
+            }
+
+            self.if_fc_pp = performance.if_fc_pp;
+            self.max_pp = performance.max_pp;
+        }
+
+        self
+    }
+
+    fn is_map_deleted(&self) -> bool {
+        self.map
+            .as_ref()
+            .is_some_and(|map| map.deleted_at.is_some())
+    }
+
     /// Count all hitobjects of the score i.e. for `GameMode::Osu` the amount 300s, 100s, 50s, and misses.
     ///
     /// Note: Includes tiny droplet (misses) for `GameMode::Catch`
@@ -136,6 +246,27 @@ impl PartialEq for Score {
 
 impl Eq for Score {}
 
+/// Calculates performance values of a [`Score`], see [`Score::with_performance`].
+///
+/// This crate does not calculate pp itself; implement this trait to
+/// attach values of a pp calculator such as `rosu-pp`.
+pub trait PerformanceProvider {
+    /// Calculate the performance of the score, `None` if it can't be calculated
+    /// e.g. because the beatmap is not available.
+    fn performance(&self, score: &Score) -> Option<ScorePerformance>;
+}
+
+/// Performance values of a [`Score`] provided by a [`PerformanceProvider`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ScorePerformance {
+    /// The pp of the score
+    pub pp: f32,
+    /// The pp if the score was a full combo
+    pub if_fc_pp: Option<f32>,
+    /// The pp of an SS with the score's mods
+    pub max_pp: Option<f32>,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct Scores {
     pub(crate) scores: Vec<Score>,
@@ -153,14 +284,39 @@ pub struct ScoreStatistics {
     pub count_geki: u32,
     #[serde(alias = "great", default)]
     pub count_300: u32,
-    #[serde(alias = "good", alias = "small_tick_miss", default)]
+    #[serde(alias = "good", default)]
     pub count_katu: u32,
-    #[serde(alias = "ok", alias = "large_tick_hit", default)]
+    #[serde(alias = "ok", default)]
     pub count_100: u32,
-    #[serde(alias = "meh", alias = "small_tick_hit", default)]
+    #[serde(alias = "meh", default)]
     pub count_50: u32,
     #[serde(alias = "miss", default)]
     pub count_miss: u32,
+    /// Slider ticks or, for osu!catch, droplets.
+    ///
+    /// Only provided in the lazer format of statistics.
+    #[serde(default)]
+    pub large_tick_hit: u32,
+    /// Missed slider ticks or, for osu!catch, missed droplets.
+    ///
+    /// Only provided in the lazer format of statistics.
+    #[serde(default)]
+    pub large_tick_miss: u32,
+    /// Tiny droplets of osu!catch.
+    ///
+    /// Only provided in the lazer format of statistics.
+    #[serde(default)]
+    pub small_tick_hit: u32,
+    /// Missed tiny droplets of osu!catch.
+    ///
+    /// Only provided in the lazer format of statistics.
+    #[serde(default)]
+    pub small_tick_miss: u32,
+    /// Hit slider ends of osu!standard.
+    ///
+    /// Only provided in the lazer format of statistics.
+    #[serde(default)]
+    pub slider_tail_hit: u32,
 }
 
 impl ScoreStatistics {
@@ -168,20 +324,38 @@ impl ScoreStatistics {
     ///
     /// Note: Includes tiny droplet (misses) for `GameMode::Catch`
     pub fn total_hits(&self, mode: GameMode) -> u32 {
+        if mode == GameMode::Catch {
+            let (fruits, droplets, tiny_droplets, tiny_droplet_misses, misses) = self.catch_hits();
+
+            return fruits + droplets + tiny_droplets + tiny_droplet_misses + misses;
+        }
+
         let mut amount = self.count_300 + self.count_100 + self.count_miss;
 
         if mode != GameMode::Taiko {
             amount += self.count_50;
 
             if mode != GameMode::Osu {
-                amount += self.count_katu;
-                amount += (mode != GameMode::Catch) as u32 * self.count_geki;
+                amount += self.count_katu + self.count_geki;
             }
         }
 
         amount
     }
 
+    /// Fruits, droplets, tiny droplets, tiny droplet misses, and misses of
+    /// an osu!catch score, regardless of whether the statistics are in the
+    /// stable or lazer format.
+    fn catch_hits(&self) -> (u32, u32, u32, u32, u32) {
+        (
+            self.count_300,
+            self.count_100 + self.large_tick_hit,
+            self.count_50 + self.small_tick_hit,
+            self.count_katu + self.small_tick_miss,
+            self.count_miss + self.large_tick_miss,
+        )
+    }
+
     /// Calculate the accuracy rounded to two decimal points i.e. `0 <= accuracy <= 100`
     pub fn accuracy(&self, mode: GameMode) -> f32 {
         let amount_objects = self.total_hits(mode) as f32;
@@ -191,10 +365,11 @@ impl ScoreStatistics {
                 0.5 * self.count_100 as f32 + self.count_300 as f32,
                 amount_objects,
             ),
-            GameMode::Catch => (
-                (self.count_300 + self.count_100 + self.count_50) as f32,
-                amount_objects,
-            ),
+            GameMode::Catch => {
+                let (fruits, droplets, tiny_droplets, ..) = self.catch_hits();
+
+                ((fruits + droplets + tiny_droplets) as f32, amount_objects)
+            }
             GameMode::Osu | GameMode::Mania => {
                 let mut n =
                     (self.count_50 * 50 + self.count_100 * 100 + self.count_300 * 300) as f32;
@@ -319,7 +494,7 @@ fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
 fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
     let accuracy = accuracy.unwrap_or_else(|| score.accuracy());
 
-    if (100.0 - accuracy).abs() <= std::f32::EPSILON {
+    if (100.0 - accuracy).abs() <= f32::EPSILON {
         if score.mods.intersects(HDFL) {
             Grade::XH
         } else {
@@ -341,3 +516,256 @@ fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
         Grade::D
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCORE: &str = r#"{
+        "accuracy": 0.9876,
+        "beatmap_id": 2,
+        "best_id": 3,
+        "ended_at": "2021-01-01T00:00:00+00:00",
+        "id": 1,
+        "max_combo": 500,
+        "mode": "osu",
+        "mods": ["HD"],
+        "passed": true,
+        "perfect": false,
+        "rank": "S",
+        "replay": false,
+        "score": 1000000,
+        "statistics": {
+            "count_300": 400,
+            "count_100": 10,
+            "count_50": 0,
+            "count_geki": 50,
+            "count_katu": 5,
+            "count_miss": 0
+        },
+        "user_id": 4
+    }"#;
+
+    #[test]
+    fn beatmap_scores_with_user_score() {
+        let json = format!(
+            r#"{{"scores":[{score}],"userScore":{{"position":7,"score":{score}}}}}"#,
+            score = SCORE
+        );
+
+        let scores: BeatmapScores = serde_json::from_str(&json).unwrap();
+        assert_eq!(scores.scores.len(), 1);
+
+        let user_score = scores.user_score.unwrap();
+        assert_eq!(user_score.pos, 7);
+        assert_eq!(user_score.score.user_id, 4);
+    }
+
+    #[test]
+    fn beatmap_scores_without_user_score() {
+        let json = format!(r#"{{"scores":[{}]}}"#, SCORE);
+        let scores: BeatmapScores = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(scores.scores.len(), 1);
+        assert!(scores.user_score.is_none());
+
+        let json = r#"{"scores":[],"userScore":null}"#;
+        let scores: BeatmapScores = serde_json::from_str(json).unwrap();
+
+        assert!(scores.scores.is_empty());
+        assert!(scores.user_score.is_none());
+    }
+
+    #[test]
+    fn lazer_osu_statistics() {
+        let json = r#"{
+            "great": 500,
+            "ok": 20,
+            "meh": 1,
+            "miss": 2,
+            "large_tick_hit": 80,
+            "slider_tail_hit": 100,
+            "ignore_hit": 100
+        }"#;
+
+        let stats: ScoreStatistics = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.count_300, 500);
+        assert_eq!(stats.count_100, 20);
+        assert_eq!(stats.large_tick_hit, 80);
+        assert_eq!(stats.slider_tail_hit, 100);
+        assert_eq!(stats.total_hits(GameMode::Osu), 523);
+    }
+
+    #[test]
+    fn lazer_catch_statistics() {
+        let stable = r#"{
+            "count_300": 300,
+            "count_100": 50,
+            "count_50": 100,
+            "count_katu": 10,
+            "count_miss": 4
+        }"#;
+
+        let lazer = r#"{
+            "great": 300,
+            "large_tick_hit": 50,
+            "small_tick_hit": 100,
+            "small_tick_miss": 10,
+            "miss": 2,
+            "large_tick_miss": 2
+        }"#;
+
+        let stable: ScoreStatistics = serde_json::from_str(stable).unwrap();
+        let lazer: ScoreStatistics = serde_json::from_str(lazer).unwrap();
+
+        assert_eq!(stable.total_hits(GameMode::Catch), 464);
+        assert_eq!(lazer.total_hits(GameMode::Catch), 464);
+        assert_eq!(
+            stable.accuracy(GameMode::Catch),
+            lazer.accuracy(GameMode::Catch)
+        );
+    }
+
+    #[test]
+    fn lazer_score() {
+        let stable: Score = serde_json::from_str(SCORE).unwrap();
+        assert!(!stable.is_lazer());
+
+        let json = SCORE.replacen('{', r#"{"build_id": 7000, "legacy_score_id": null,"#, 1);
+        let lazer: Score = serde_json::from_str(&json).unwrap();
+
+        assert!(lazer.is_lazer());
+        assert_eq!(lazer.legacy_score_id, None);
+    }
+
+    struct FixedPerformance(Option<ScorePerformance>);
+
+    impl PerformanceProvider for FixedPerformance {
+        fn performance(&self, _: &Score) -> Option<ScorePerformance> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn with_performance() {
+        let provider = FixedPerformance(Some(ScorePerformance {
+            pp: 123.0,
+            if_fc_pp: Some(150.0),
+            max_pp: Some(200.0),
+        }));
+
+        let loved: Score = serde_json::from_str(SCORE).unwrap();
+        let loved = loved.with_performance(&provider);
+        assert_eq!(loved.pp, Some(123.0));
+        assert_eq!(loved.if_fc_pp, Some(150.0));
+        assert_eq!(loved.max_pp, Some(200.0));
+
+        let json = SCORE.replacen('{', r#"{"pp": 100.0,"#, 1);
+        let ranked: Score = serde_json::from_str(&json).unwrap();
+        let ranked = ranked.with_performance(&provider);
+        assert_eq!(ranked.pp, Some(100.0));
+        assert_eq!(ranked.max_pp, Some(200.0));
+
+        let unknown: Score = serde_json::from_str(SCORE).unwrap();
+        let unknown = unknown.with_performance(&FixedPerformance(None));
+        assert_eq!(unknown.pp, None);
+        assert_eq!(unknown.if_fc_pp, None);
+    }
+
+    #[test]
+    fn score_without_pp() {
+        let json = SCORE.replacen('{', r#"{"pp": null,"#, 1);
+        let loved: Score = serde_json::from_str(&json).unwrap();
+        assert_eq!(loved.pp, None);
+
+        let missing: Score = serde_json::from_str(SCORE).unwrap();
+        assert_eq!(missing.pp, None);
+
+        let json = SCORE.replacen('{', r#"{"pp": 0,"#, 1);
+        let zero: Score = serde_json::from_str(&json).unwrap();
+        assert_eq!(zero.pp, Some(0.0));
+    }
+
+    const MAP: &str = r#"{
+        "accuracy": 9.0,
+        "ar": 9.3,
+        "beatmapset_id": 5,
+        "bpm": 180.0,
+        "convert": false,
+        "count_circles": 400,
+        "count_sliders": 200,
+        "count_spinners": 1,
+        "cs": 4.0,
+        "difficulty_rating": 5.5,
+        "drain": 5.0,
+        "hit_length": 180,
+        "id": 2,
+        "is_scoreable": true,
+        "last_updated": "2021-01-01T00:00:00+00:00",
+        "mode": "osu",
+        "passcount": 100,
+        "playcount": 1000,
+        "status": "ranked",
+        "total_length": 200,
+        "url": "https://osu.ppy.sh/beatmaps/2",
+        "user_id": 4,
+        "version": "Insane"
+    }"#;
+
+    #[test]
+    fn map_urls() {
+        let score: Score = serde_json::from_str(SCORE).unwrap();
+        assert_eq!(
+            score.map_url().as_deref(),
+            Some("https://osu.ppy.sh/beatmaps/2")
+        );
+        assert_eq!(score.mapset_url(), None);
+
+        let json = SCORE.replacen('{', &format!(r#"{{"beatmap": {MAP},"#), 1);
+        let score: Score = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            score.mapset_url().as_deref(),
+            Some("https://osu.ppy.sh/beatmapsets/5")
+        );
+
+        let deleted = MAP.replacen('{', r#"{"deleted_at": "2022-01-01T00:00:00+00:00","#, 1);
+        let json = SCORE.replacen('{', &format!(r#"{{"beatmap": {deleted},"#), 1);
+        let score: Score = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(score.map_url(), None);
+        assert_eq!(score.mapset_url(), None);
+    }
+
+    #[test]
+    fn replay_urls() {
+        let score: Score = serde_json::from_str(SCORE).unwrap();
+        assert_eq!(score.replay_url(), None);
+
+        let json = SCORE.replacen(r#""replay": false"#, r#""replay": true"#, 1);
+        let stable: Score = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            stable.replay_url().as_deref(),
+            Some("https://osu.ppy.sh/scores/osu/1/download")
+        );
+
+        let converted = json.replacen('{', r#"{"legacy_score_id": 123,"#, 1);
+        let converted: Score = serde_json::from_str(&converted).unwrap();
+
+        assert!(!converted.is_lazer());
+        assert_eq!(
+            converted.replay_url().as_deref(),
+            Some("https://osu.ppy.sh/scores/1/download")
+        );
+
+        let json = json.replacen('{', r#"{"build_id": 7000,"#, 1);
+        let lazer: Score = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            lazer.replay_url().as_deref(),
+            Some("https://osu.ppy.sh/scores/1/download")
+        );
+    }
+}
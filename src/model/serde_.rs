@@ -234,3 +234,16 @@ pub(super) mod date {
         }
     }
 }
+
+/// Emit a warning for a field that the API provided but that is not (yet) known to rosu-v2.
+///
+/// The warning goes through `tracing` if the `tracing` feature is enabled, otherwise through `log`.
+#[cfg(feature = "log-unknown-fields")]
+#[cold]
+pub(super) fn log_unknown_field(ty: &str, field: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(ty, field, "Unknown field `{field}` in {ty}");
+
+    #[cfg(not(feature = "tracing"))]
+    warn!("Unknown field `{field}` in {ty}");
+}
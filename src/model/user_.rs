@@ -1,11 +1,12 @@
 use super::{serde_, GameMode};
+use crate::error::UsernameError;
 
 use serde::{
     de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
     Deserialize, Deserializer,
 };
 use smallstr::SmallString;
-use std::fmt;
+use std::{borrow::Cow, fmt};
 use time::{Date, OffsetDateTime};
 
 #[cfg(feature = "rkyv")]
@@ -186,6 +187,47 @@ pub enum HistoryType {
     Silence,
 }
 
+/// The category of a user's mapsets to request through
+/// [`GetUserBeatmapsets::map_type`](crate::request::GetUserBeatmapsets::map_type).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MapsetType {
+    /// Mapsets that the user favourited
+    Favourite,
+    /// Mapsets of the user that are in the graveyard
+    Graveyard,
+    /// Mapsets on which the user has a guest difficulty
+    Guest,
+    /// Loved mapsets of the user
+    Loved,
+    /// Mapsets that the user nominated
+    Nominated,
+    /// Mapsets of the user that are pending, work in progress, or qualified
+    Pending,
+    /// Ranked or approved mapsets of the user
+    Ranked,
+}
+
+impl MapsetType {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Favourite => "favourite",
+            Self::Graveyard => "graveyard",
+            Self::Guest => "guest",
+            Self::Loved => "loved",
+            Self::Nominated => "nominated",
+            Self::Pending => "pending",
+            Self::Ranked => "ranked",
+        }
+    }
+}
+
+impl fmt::Display for MapsetType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -438,6 +480,9 @@ pub struct User {
     pub is_moderator: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_nat: Option<bool>,
+    /// Whether the user is restricted, only provided for the authenticated user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_restricted: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_silenced: Option<bool>,
     #[serde(
@@ -476,8 +521,14 @@ pub struct User {
     pub scores_first_count: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scores_recent_count: Option<u32>,
+    /// Whether the current session is verified, only provided for the authenticated user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_verified: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub statistics: Option<UserStatistics>,
+    /// Statistics for each mode, only provided for some endpoints
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statistics_rulesets: Option<UserStatisticsRulesets>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub support_level: Option<u8>,
     #[serde(
@@ -494,6 +545,60 @@ pub struct User {
     pub medals: Option<Vec<MedalCompact>>,
 }
 
+impl User {
+    /// The user's avatar url, guaranteed to be absolute.
+    ///
+    /// The API sometimes provides protocol-relative urls such as `//a.ppy.sh/123`
+    /// which will be prefixed with `https:`, or relative paths such as
+    /// `/images/layout/avatar-guest.png` which will be prefixed with `https://osu.ppy.sh`.
+    #[inline]
+    pub fn absolute_avatar_url(&self) -> Cow<'_, str> {
+        absolute_avatar_url(&self.avatar_url)
+    }
+
+    /// The sum of all monthly playcounts; `0` if `monthly_playcounts` is `None`.
+    #[inline]
+    pub fn total_monthly_playcount(&self) -> u64 {
+        sum_monthly_counts(self.monthly_playcounts.as_deref(), None)
+    }
+
+    /// The sum of monthly playcounts of all months starting on or after `since`;
+    /// `0` if `monthly_playcounts` is `None`.
+    ///
+    /// Note that the playcount of the month that `since` falls into is only
+    /// included if `since` is the first day of that month.
+    #[inline]
+    pub fn playcount_since(&self, since: Date) -> u64 {
+        sum_monthly_counts(self.monthly_playcounts.as_deref(), Some(since))
+    }
+}
+
+fn sum_monthly_counts(counts: Option<&[MonthlyCount]>, since: Option<Date>) -> u64 {
+    let total: i64 = counts
+        .unwrap_or_default()
+        .iter()
+        .filter(|count| match since {
+            Some(since) => count.start_date >= since,
+            None => true,
+        })
+        .map(|count| i64::from(count.count))
+        .sum();
+
+    total.max(0) as u64
+}
+
+fn absolute_avatar_url(url: &str) -> Cow<'_, str> {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Cow::Borrowed(url)
+    } else if url.starts_with("//") {
+        Cow::Owned(format!("https:{}", url))
+    } else if url.starts_with('/') {
+        Cow::Owned(format!("https://osu.ppy.sh{}", url))
+    } else {
+        Cow::Owned(format!("https://{}", url))
+    }
+}
+
 /// Mainly used for embedding in certain responses to save additional api lookups.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -601,6 +706,9 @@ pub struct UserCompact {
     pub is_nat: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_silenced: Option<bool>,
+    /// Only provided for some endpoints, e.g. [`Osu::kudosu_rankings`](crate::Osu::kudosu_rankings)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kudosu: Option<UserKudosu>,
     #[serde(
         default,
         rename = "loved_beatmapset_count",
@@ -643,6 +751,9 @@ pub struct UserCompact {
     pub scores_recent_count: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub statistics: Option<UserStatistics>,
+    /// Statistics for each mode, only provided for some endpoints
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statistics_rulesets: Option<UserStatisticsRulesets>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub support_level: Option<u8>,
     #[serde(
@@ -651,6 +762,45 @@ pub struct UserCompact {
         skip_serializing_if = "Option::is_none"
     )]
     pub pending_mapset_count: Option<u32>,
+    /// All fields of the API response that are not modeled (yet).
+    ///
+    /// Only available with the `raw-extra` feature. The captured keys are **unstable**,
+    /// i.e. once a key is modeled as a proper field in a later release,
+    /// it will no longer be part of this value.
+    #[cfg(feature = "raw-extra")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::JsonValueWrapper))]
+    pub extra: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Users {
+    pub(crate) users: Vec<UserCompact>,
+}
+
+impl UserCompact {
+    /// The user's avatar url, guaranteed to be absolute.
+    ///
+    /// See [`User::absolute_avatar_url`].
+    #[inline]
+    pub fn absolute_avatar_url(&self) -> Cow<'_, str> {
+        absolute_avatar_url(&self.avatar_url)
+    }
+
+    /// The sum of all monthly playcounts; `0` if `monthly_playcounts` is `None`.
+    #[inline]
+    pub fn total_monthly_playcount(&self) -> u64 {
+        sum_monthly_counts(self.monthly_playcounts.as_deref(), None)
+    }
+
+    /// The sum of monthly playcounts of all months starting on or after `since`;
+    /// `0` if `monthly_playcounts` is `None`.
+    ///
+    /// See [`User::playcount_since`].
+    #[inline]
+    pub fn playcount_since(&self, since: Date) -> u64 {
+        sum_monthly_counts(self.monthly_playcounts.as_deref(), Some(since))
+    }
 }
 
 impl From<User> for UserCompact {
@@ -688,6 +838,7 @@ impl From<User> for UserCompact {
             is_moderator: user.is_moderator,
             is_nat: user.is_nat,
             is_silenced: user.is_silenced,
+            kudosu: Some(user.kudosu),
             loved_mapset_count: user.loved_mapset_count,
             medals: user.medals,
             monthly_playcounts: user.monthly_playcounts,
@@ -700,8 +851,11 @@ impl From<User> for UserCompact {
             scores_first_count: user.scores_first_count,
             scores_recent_count: user.scores_recent_count,
             statistics: user.statistics,
+            statistics_rulesets: user.statistics_rulesets,
             support_level: user.support_level,
             pending_mapset_count: user.pending_mapset_count,
+            #[cfg(feature = "raw-extra")]
+            extra: serde_json::Value::Object(Default::default()),
         }
     }
 }
@@ -777,6 +931,93 @@ impl UserLevel {
 /// osu! usernames are at most 15 ASCII characters long
 pub type Username = SmallString<[u8; 15]>;
 
+/// Create a [`Username`] while checking that it follows osu!'s rules for usernames.
+///
+/// A valid username
+/// - is between 3 and 15 characters long
+/// - consists only of ASCII letters, digits, spaces, and the characters `-`, `_`, `[`, `]`
+/// - does not contain both spaces and underscores
+/// - does not start or end with a space and does not contain consecutive spaces
+///
+/// Converting into a [`Username`] through `From` does not perform any checks.
+///
+/// # Example
+///
+/// ```
+/// use rosu_v2::{error::UsernameError, model::user::validate_username};
+///
+/// assert!(validate_username("peppy").is_ok());
+/// assert_eq!(validate_username("ab"), Err(UsernameError::TooShort(2)));
+/// assert_eq!(validate_username("a_b c"), Err(UsernameError::MixedSpacesAndUnderscores));
+/// ```
+pub fn validate_username(name: &str) -> Result<Username, UsernameError> {
+    let len = name.chars().count();
+
+    if len < 3 {
+        return Err(UsernameError::TooShort(len));
+    } else if len > 15 {
+        return Err(UsernameError::TooLong(len));
+    }
+
+    let invalid = name
+        .chars()
+        .find(|&c| !(c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_' | '[' | ']')));
+
+    if let Some(c) = invalid {
+        return Err(UsernameError::InvalidCharacter(c));
+    }
+
+    if name.contains(' ') && name.contains('_') {
+        return Err(UsernameError::MixedSpacesAndUnderscores);
+    }
+
+    if name.starts_with(' ') || name.ends_with(' ') || name.contains("  ") {
+        return Err(UsernameError::InvalidSpaces);
+    }
+
+    Ok(Username::from_str(name))
+}
+
+/// Normalize a username the way osu! does when looking up users,
+/// i.e. lowercase it and treat spaces the same as underscores.
+///
+/// # Example
+///
+/// ```
+/// use rosu_v2::model::user::normalize_username;
+///
+/// assert_eq!(normalize_username("Foo Bar"), "foo_bar");
+/// ```
+pub fn normalize_username(name: &str) -> String {
+    name.chars().map(normalize_username_char).collect()
+}
+
+/// Check whether two usernames refer to the same user, i.e. whether they
+/// are equal after [normalization](normalize_username).
+///
+/// # Example
+///
+/// ```
+/// use rosu_v2::model::user::username_eq_ignore_case;
+///
+/// assert!(username_eq_ignore_case("Foo Bar", "foo_BAR"));
+/// assert!(!username_eq_ignore_case("foo", "foo bar"));
+/// ```
+pub fn username_eq_ignore_case(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.chars()
+            .map(normalize_username_char)
+            .eq(b.chars().map(normalize_username_char))
+}
+
+#[inline]
+fn normalize_username_char(c: char) -> char {
+    match c {
+        ' ' => '_',
+        _ => c.to_ascii_lowercase(),
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -790,7 +1031,7 @@ pub struct UserPage {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct UserStatistics {
-    /// Hit accuracy percentage
+    /// Hit accuracy percentage i.e. `0.0 <= accuracy <= 100.0`
     #[serde(rename = "hit_accuracy")]
     pub accuracy: f32,
     /// Current country rank according to pp
@@ -804,8 +1045,10 @@ pub struct UserStatistics {
     pub is_ranked: bool,
     /// The user's level progression
     pub level: UserLevel,
-    /// Highest maximum combo
-    #[serde(rename = "maximum_combo")]
+    /// Highest maximum combo.
+    ///
+    /// `0` if the API did not provide it, e.g. for some inactive users.
+    #[serde(default, rename = "maximum_combo", deserialize_with = "maybe_u32")]
     pub max_combo: u32,
     /// Number of maps played
     #[serde(rename = "play_count")]
@@ -813,13 +1056,22 @@ pub struct UserStatistics {
     /// Cumulative time played in seconds
     #[serde(rename = "play_time", deserialize_with = "maybe_u32")]
     pub playtime: u32,
-    /// Performance points
-    #[serde(deserialize_with = "deserialize_f32_default")]
-    pub pp: f32,
+    /// Performance points.
+    ///
+    /// `None` if the API provided no pp, e.g. for inactive users,
+    /// which is not to be confused with `Some(0.0)`.
+    #[serde(default)]
+    pub pp: Option<f32>,
     /// Current ranked score
     pub ranked_score: u64,
-    /// Number of replays watched by other users
-    #[serde(rename = "replays_watched_by_others")]
+    /// Number of replays watched by other users.
+    ///
+    /// `0` if the API did not provide it.
+    #[serde(
+        default,
+        rename = "replays_watched_by_others",
+        deserialize_with = "maybe_u32"
+    )]
     pub replays_watched: u32,
     /// Total number of hits
     pub total_hits: u64,
@@ -827,9 +1079,59 @@ pub struct UserStatistics {
     pub total_score: u64,
 }
 
-#[inline]
-fn deserialize_f32_default<'de, D: Deserializer<'de>>(d: D) -> Result<f32, D::Error> {
-    <Option<f32> as Deserialize>::deserialize(d).map(Option::unwrap_or_default)
+impl UserStatistics {
+    /// The hit accuracy as percentage i.e. `0.0 <= accuracy <= 100.0`.
+    ///
+    /// This is the same value as the `accuracy` field, e.g. `98.76` for 98.76%,
+    /// and **not** a ratio between `0.0` and `1.0`.
+    #[inline]
+    pub fn accuracy_percent(&self) -> f32 {
+        self.accuracy
+    }
+
+    /// The sum of all grade counts.
+    ///
+    /// Grade counts provided by the API may be negative in rare cases
+    /// which is why the sum is capped at `0` from below.
+    #[inline]
+    pub fn grade_counts_total(&self) -> u32 {
+        let GradeCounts { ss, ssh, s, sh, a } = self.grade_counts;
+        let total = i64::from(ss) + i64::from(ssh) + i64::from(s) + i64::from(sh) + i64::from(a);
+
+        total.max(0) as u32
+    }
+}
+
+/// [`UserStatistics`] of a [`User`] for each [`GameMode`]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct UserStatisticsRulesets {
+    /// Statistics for osu!standard
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub osu: Option<UserStatistics>,
+    /// Statistics for osu!taiko
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taiko: Option<UserStatistics>,
+    /// Statistics for osu!catch
+    #[serde(default, rename = "fruits", skip_serializing_if = "Option::is_none")]
+    pub catch: Option<UserStatistics>,
+    /// Statistics for osu!mania
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mania: Option<UserStatistics>,
+}
+
+impl UserStatisticsRulesets {
+    /// The statistics of the given mode, if available
+    #[inline]
+    pub fn get(&self, mode: GameMode) -> Option<&UserStatistics> {
+        match mode {
+            GameMode::Osu => self.osu.as_ref(),
+            GameMode::Taiko => self.taiko.as_ref(),
+            GameMode::Catch => self.catch.as_ref(),
+            GameMode::Mania => self.mania.as_ref(),
+        }
+    }
 }
 
 #[inline]
@@ -893,3 +1195,169 @@ impl<'de> Visitor<'de> for RankHistoryVisitor {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn avatar_url_absolute() {
+        let url = "https://a.ppy.sh/2?1621384809.jpeg";
+        assert_eq!(absolute_avatar_url(url), url);
+    }
+
+    #[test]
+    fn avatar_url_protocol_relative() {
+        let url = "//a.ppy.sh/2?1621384809.jpeg";
+        assert_eq!(
+            absolute_avatar_url(url),
+            "https://a.ppy.sh/2?1621384809.jpeg"
+        );
+    }
+
+    #[test]
+    fn avatar_url_relative() {
+        let url = "/images/layout/avatar-guest.png";
+
+        assert_eq!(
+            absolute_avatar_url(url),
+            "https://osu.ppy.sh/images/layout/avatar-guest.png"
+        );
+    }
+
+    #[test]
+    fn user_stats_missing_combo_and_replays() {
+        let json = r#"{
+            "hit_accuracy": 98.76,
+            "global_rank": null,
+            "grade_counts": { "ss": 1, "ssh": 2, "s": 3, "sh": 4, "a": 5 },
+            "is_ranked": false,
+            "level": { "current": 100, "progress": 25 },
+            "play_count": 1234,
+            "play_time": null,
+            "pp": null,
+            "ranked_score": 123456,
+            "total_hits": 12345,
+            "total_score": 1234567
+        }"#;
+
+        let stats: UserStatistics = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.max_combo, 0);
+        assert_eq!(stats.replays_watched, 0);
+    }
+
+    #[test]
+    fn user_stats_accuracy_percent() {
+        let json = r#"{
+            "hit_accuracy": 98.76,
+            "global_rank": 1000,
+            "grade_counts": { "ss": 1, "ssh": 2, "s": 3, "sh": 4, "a": 5 },
+            "is_ranked": true,
+            "level": { "current": 100, "progress": 25 },
+            "play_count": 1234,
+            "play_time": 3600,
+            "pp": 5000.0,
+            "ranked_score": 123456,
+            "total_hits": 12345,
+            "total_score": 1234567
+        }"#;
+
+        let stats: UserStatistics = serde_json::from_str(json).unwrap();
+
+        assert!((stats.accuracy_percent() - 98.76).abs() < f32::EPSILON);
+        assert_eq!(stats.grade_counts_total(), 15);
+    }
+
+    #[test]
+    #[cfg(feature = "raw-extra")]
+    fn user_compact_raw_extra() {
+        let json = r#"{
+            "avatar_url": "",
+            "country_code": "BE",
+            "default_group": "default",
+            "id": 2,
+            "is_active": true,
+            "is_bot": false,
+            "is_deleted": false,
+            "is_online": false,
+            "is_supporter": false,
+            "pm_friends_only": false,
+            "username": "peppy",
+            "rank_history": { "mode": "osu", "data": [3, 2, 1] },
+            "some_new_field": { "a": 1 }
+        }"#;
+
+        let user: UserCompact = serde_json::from_str(json).unwrap();
+
+        assert_eq!(user.rank_history, Some(vec![3, 2, 1]));
+        assert_eq!(
+            user.extra,
+            serde_json::json!({ "some_new_field": { "a": 1 } })
+        );
+    }
+
+    #[test]
+    fn username_validation() {
+        assert_eq!(validate_username("peppy").unwrap(), "peppy");
+        assert!(validate_username("[Foo-Bar]_2").is_ok());
+        assert!(validate_username("foo bar").is_ok());
+
+        assert_eq!(validate_username("ab"), Err(UsernameError::TooShort(2)));
+        assert_eq!(
+            validate_username("a_very_long_username"),
+            Err(UsernameError::TooLong(20))
+        );
+        assert_eq!(
+            validate_username("foo.bar"),
+            Err(UsernameError::InvalidCharacter('.'))
+        );
+        assert_eq!(
+            validate_username("foo_b ar"),
+            Err(UsernameError::MixedSpacesAndUnderscores)
+        );
+        assert_eq!(validate_username(" foo"), Err(UsernameError::InvalidSpaces));
+        assert_eq!(
+            validate_username("foo  bar"),
+            Err(UsernameError::InvalidSpaces)
+        );
+    }
+
+    fn monthly_counts() -> Vec<MonthlyCount> {
+        let month = |year, month, count| MonthlyCount {
+            start_date: Date::from_calendar_date(year, month, 1).unwrap(),
+            count,
+        };
+
+        vec![
+            month(2020, time::Month::November, 10),
+            month(2020, time::Month::December, 20),
+            month(2021, time::Month::January, 30),
+        ]
+    }
+
+    #[test]
+    fn monthly_counts_total() {
+        assert_eq!(sum_monthly_counts(Some(&monthly_counts()), None), 60);
+        assert_eq!(sum_monthly_counts(None, None), 0);
+    }
+
+    #[test]
+    fn monthly_counts_since() {
+        let counts = monthly_counts();
+        let since = |month, day| Date::from_calendar_date(2020, month, day).unwrap();
+
+        assert_eq!(
+            sum_monthly_counts(Some(&counts), Some(since(time::Month::December, 1))),
+            50
+        );
+        assert_eq!(
+            sum_monthly_counts(Some(&counts), Some(since(time::Month::November, 15))),
+            50
+        );
+        assert_eq!(
+            sum_monthly_counts(None, Some(since(time::Month::May, 1))),
+            0
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
+    error::OsuError,
     model::{
         beatmap::{
-            Beatmap, Beatmapset, BeatmapsetEvents, BeatmapsetSearchResult, BeatmapsetSearchSort,
-            Genre, Language, RankStatus,
+            Beatmap, Beatmapset, BeatmapsetEventType, BeatmapsetEvents, BeatmapsetSearchResult,
+            BeatmapsetSearchSort, Genre, Language, RankStatus,
         },
         beatmap_::{
             BeatmapDifficultyAttributes, BeatmapDifficultyAttributesWrapper, Beatmaps,
@@ -14,14 +15,17 @@ use crate::{
     prelude::BeatmapCompact,
     request::{Pending, Query, Request},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
 use futures::future::TryFutureExt;
 use std::{
+    collections::{HashMap, HashSet},
     fmt::{Display, Formatter, Result as FmtResult, Write},
     mem,
 };
+use time::Date;
+use url::form_urlencoded::byte_serialize;
 
 use super::Body;
 #[cfg(feature = "cache")]
@@ -57,7 +61,7 @@ impl<'a> GetBeatmap<'a> {
         self
     }
 
-    /// Specify a beatmap filename
+    /// Specify a beatmap filename, e.g. `Artist - Title (Mapper) [Version].osu`
     #[inline]
     pub fn filename(mut self, filename: impl Into<String>) -> Self {
         self.filename.replace(filename.into());
@@ -84,6 +88,8 @@ impl<'a> GetBeatmap<'a> {
         }
 
         if let Some(ref filename) = self.filename {
+            // Filenames may contain `&`, `+`, or `#` so they need to be encoded
+            let filename: String = byte_serialize(filename.as_bytes()).collect();
             query.push("filename", filename);
         }
 
@@ -103,11 +109,15 @@ impl<'a> GetBeatmap<'a> {
 poll_req!(GetBeatmap => Beatmap);
 
 /// Get a vec of [`BeatmapCompact`](crate::model::beatmap::BeatmapCompact) by their map ids.
+///
+/// The ids are requested in chunks of 50, the maximum amount the API allows per request.
+/// Maps are returned in the order of their ids, duplicate ids are only requested once,
+/// and ids of maps that the API does not provide are skipped.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmaps<'a> {
     fut: Option<Pending<'a, Vec<BeatmapCompact>>>,
     osu: &'a Osu,
-    query: Query,
+    map_ids: Vec<u32>,
 }
 
 impl<'a> GetBeatmaps<'a> {
@@ -116,28 +126,48 @@ impl<'a> GetBeatmaps<'a> {
     where
         I: IntoIterator<Item = u32>,
     {
-        let mut query = Query::new();
+        let mut seen = HashSet::new();
 
-        for map_id in map_ids.into_iter().take(50) {
-            query.push("ids[]", map_id);
-        }
+        let map_ids = map_ids
+            .into_iter()
+            .filter(|map_id| seen.insert(*map_id))
+            .collect();
 
         Self {
             fut: None,
             osu,
-            query,
+            map_ids,
         }
     }
 
     fn start(&mut self) -> Pending<'a, Vec<BeatmapCompact>> {
-        #[cfg(feature = "metrics")]
-        self.osu.metrics.beatmaps.inc();
-
-        let query = mem::take(&mut self.query);
-        let req = Request::with_query(Route::GetBeatmaps, query);
+        let map_ids = mem::take(&mut self.map_ids);
         let osu = self.osu;
 
-        let fut = osu.request::<Beatmaps>(req).map_ok(|maps| maps.maps);
+        let fut = async move {
+            let mut maps = HashMap::with_capacity(map_ids.len());
+
+            for chunk in map_ids.chunks(50) {
+                #[cfg(feature = "metrics")]
+                osu.metrics.beatmaps.inc();
+
+                let mut query = Query::new();
+                query.push_all("ids", chunk);
+
+                let req = Request::with_query(Route::GetBeatmaps, query);
+
+                for map in osu.request::<Beatmaps>(req).await?.maps {
+                    maps.insert(map.map_id, map);
+                }
+            }
+
+            let maps = map_ids
+                .iter()
+                .filter_map(|map_id| maps.remove(map_id))
+                .collect();
+
+            Ok(maps)
+        };
 
         Box::pin(fut)
     }
@@ -166,7 +196,9 @@ impl<'a> GetBeatmapDifficultyAttributes<'a> {
         }
     }
 
-    /// Specify the mode
+    /// Specify the mode, e.g. to get the attributes of an osu!standard map converted to another mode.
+    ///
+    /// The mode determines the variant of [`GameModeAttributes`](crate::model::beatmap::GameModeAttributes).
     #[inline]
     pub fn mode(mut self, mode: GameMode) -> Self {
         self.mode = Some(mode);
@@ -230,11 +262,11 @@ impl Display for ScoreType {
     }
 }
 
-/// Get top scores of a beatmap by its id in form of a
-/// vec of [`Score`](Score)s.
+/// Get top scores of a beatmap by its id in form of
+/// [`BeatmapScores`](crate::model::score::BeatmapScores).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapScores<'a> {
-    fut: Option<Pending<'a, Vec<Score>>>,
+    fut: Option<Pending<'a, BeatmapScores>>,
     osu: &'a Osu,
     map_id: u32,
     score_type: Option<ScoreType>,
@@ -268,7 +300,11 @@ impl<'a> GetBeatmapScores<'a> {
         self
     }
 
-    /// Specify the mods of the scores
+    /// Only include scores with exactly the given mod combination, e.g. a HDHR leaderboard.
+    ///
+    /// Each mod is sent as its own repeated `mods[]` query parameter,
+    /// i.e. `HDHR` becomes `mods[]=HD&mods[]=HR`.
+    /// [`GameMods::NoMod`] is sent as `mods[]=NM` and only includes scores without mods.
     #[inline]
     pub fn mods(mut self, mods: GameMods) -> Self {
         self.mods.replace(mods);
@@ -308,20 +344,18 @@ impl<'a> GetBeatmapScores<'a> {
     //     self
     // }
 
-    fn start(&mut self) -> Pending<'a, Vec<Score>> {
+    fn start(&mut self) -> Pending<'a, BeatmapScores> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmap_scores.inc();
 
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         if let Some(mods) = self.mods {
-            for m in mods {
-                query.push("mods[]", &m.to_string());
-            }
+            query.push_all("mods", mods);
         }
 
         if let Some(score_type) = self.score_type {
@@ -342,11 +376,13 @@ impl<'a> GetBeatmapScores<'a> {
 
         let req = Request::with_query(route, query);
         let osu = self.osu;
-        let fut = osu.request::<BeatmapScores>(req).map_ok(|s| s.scores);
+        let fut = osu.request::<BeatmapScores>(req);
 
         #[cfg(feature = "cache")]
         let fut = fut.inspect_ok(move |scores| {
-            for score in scores.iter() {
+            let user_score = scores.user_score.as_ref().map(|score| &score.score);
+
+            for score in scores.scores.iter().chain(user_score) {
                 if let Some(ref user) = score.user {
                     osu.update_cache(user.user_id, &user.username);
                 }
@@ -357,16 +393,19 @@ impl<'a> GetBeatmapScores<'a> {
     }
 }
 
-poll_req!(GetBeatmapScores => Vec<Score>);
+poll_req!(GetBeatmapScores => BeatmapScores);
 
 /// Get [`BeatmapUserScore`](crate::model::score::BeatmapUserScore)
 /// of a user on a beatmap by the user's and the map's id.
 ///
 /// Note that the contained score will be the user's play on the map
 /// with the most **score** across all mods, not pp.
+///
+/// Resolves to `None` if the user has no score on the map. Since the API
+/// responds the same way for an unknown map or user, those also resolve to `None`.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapUserScore<'a> {
-    fut: Option<Pending<'a, BeatmapUserScore>>,
+    fut: Option<Pending<'a, Option<BeatmapUserScore>>>,
     osu: &'a Osu,
     map_id: u32,
     mode: Option<GameMode>,
@@ -422,20 +461,18 @@ impl<'a> GetBeatmapUserScore<'a> {
         self
     }
 
-    fn start(&mut self) -> Pending<'a, BeatmapUserScore> {
+    fn start(&mut self) -> Pending<'a, Option<BeatmapUserScore>> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmap_user_score.inc();
 
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         if let Some(mods) = self.mods {
-            for m in mods {
-                query.push("mods[]", &m.to_string());
-            }
+            query.push_all("mods", mods);
         }
 
         let osu = self.osu;
@@ -449,7 +486,7 @@ impl<'a> GetBeatmapUserScore<'a> {
 
             let req = Request::with_query(route, query);
 
-            Box::pin(osu.request(req))
+            Box::pin(request_user_score(osu, req))
         }
 
         #[cfg(feature = "cache")]
@@ -457,25 +494,40 @@ impl<'a> GetBeatmapUserScore<'a> {
             let map_id = self.map_id;
             let user_id = mem::replace(&mut self.user_id, UserId::Id(0));
 
-            let fut = self
-                .osu
-                .cache_user(user_id)
-                .map_ok(move |user_id| {
-                    Request::with_query(Route::GetBeatmapUserScore { user_id, map_id }, query)
-                })
-                .and_then(move |req| osu.request::<BeatmapUserScore>(req))
-                .inspect_ok(move |score| {
-                    if let Some(ref user) = score.score.user {
-                        osu.update_cache(user.user_id, &user.username);
-                    }
-                });
+            let fut = async move {
+                // An unknown username resolves to `None` just like an unknown user id
+                let user_id = match osu.cache_user(user_id).await {
+                    Ok(user_id) => user_id,
+                    Err(OsuError::NotFound) => return Ok(None),
+                    Err(err) => return Err(err),
+                };
+
+                let route = Route::GetBeatmapUserScore { user_id, map_id };
+                let score = request_user_score(osu, Request::with_query(route, query)).await?;
+                let user = score.as_ref().and_then(|score| score.score.user.as_ref());
+
+                if let Some(user) = user {
+                    osu.update_cache(user.user_id, &user.username);
+                }
+
+                Ok(score)
+            };
 
             Box::pin(fut)
         }
     }
 }
 
-poll_req!(GetBeatmapUserScore => BeatmapUserScore);
+poll_req!(GetBeatmapUserScore => Option<BeatmapUserScore>);
+
+/// The API responds with 404 if the user has no score on the map
+async fn request_user_score(osu: &Osu, req: Request) -> OsuResult<Option<BeatmapUserScore>> {
+    match osu.request(req).await {
+        Ok(score) => Ok(Some(score)),
+        Err(OsuError::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
 
 /// Get the top score with each mod combination of a user on
 /// a map in the form of a vec of [`Score`]s.
@@ -533,7 +585,7 @@ impl<'a> GetBeatmapUserScores<'a> {
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         let osu = self.osu;
@@ -652,23 +704,116 @@ impl<'a> GetBeatmapsetFromMapId<'a> {
 poll_req!(GetBeatmapsetFromMapId => Beatmapset);
 
 /// Get a [`BeatmapsetEvents`](crate::model::beatmap::BeatmapsetEvents) struct.
+///
+/// Events can be filtered by their user, type, and date.
+/// The API does not provide a cursor for events so further
+/// events are requested through [`page`](GetBeatmapsetEvents::page).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapsetEvents<'a> {
     fut: Option<Pending<'a, BeatmapsetEvents>>,
     osu: &'a Osu,
+    user_id: Option<u32>,
+    types: Vec<BeatmapsetEventType>,
+    min_date: Option<Date>,
+    max_date: Option<Date>,
+    page: Option<u32>,
+    limit: Option<u32>,
 }
 
 impl<'a> GetBeatmapsetEvents<'a> {
     #[inline]
     pub(crate) fn new(osu: &'a Osu) -> Self {
-        Self { fut: None, osu }
+        Self {
+            fut: None,
+            osu,
+            user_id: None,
+            types: Vec::new(),
+            min_date: None,
+            max_date: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    /// Only include events of the given user, e.g. the nominator.
+    #[inline]
+    pub fn user(mut self, user_id: u32) -> Self {
+        self.user_id.replace(user_id);
+
+        self
+    }
+
+    /// Only include events of the given types, e.g.
+    /// [`Nominate`](BeatmapsetEventType::Nominate) and
+    /// [`Disqualify`](BeatmapsetEventType::Disqualify).
+    #[inline]
+    pub fn types(mut self, types: &[BeatmapsetEventType]) -> Self {
+        self.types = types.to_vec();
+
+        self
+    }
+
+    /// Only include events on or after the given date.
+    #[inline]
+    pub fn min_date(mut self, date: Date) -> Self {
+        self.min_date.replace(date);
+
+        self
+    }
+
+    /// Only include events on or before the given date.
+    #[inline]
+    pub fn max_date(mut self, date: Date) -> Self {
+        self.max_date.replace(date);
+
+        self
+    }
+
+    /// Specify a page, starting at 1.
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    /// Specify the amount of events per page.
+    #[inline]
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit.replace(limit);
+
+        self
     }
 
     fn start(&mut self) -> Pending<'a, BeatmapsetEvents> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmapset_events.inc();
 
-        let req = Request::new(Route::GetBeatmapsetEvents);
+        let mut query = Query::new();
+
+        if let Some(user_id) = self.user_id {
+            query.push("user", user_id);
+        }
+
+        query.push_all("types", &self.types);
+
+        if let Some(min_date) = self.min_date {
+            query.push("min_date", min_date);
+        }
+
+        if let Some(max_date) = self.max_date {
+            query.push("max_date", max_date);
+        }
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        let req = Request::with_query(Route::GetBeatmapsetEvents, query);
 
         Box::pin(self.osu.request(req))
     }
@@ -753,7 +898,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify the mode for which the mapsets has to have at least one map.
     #[inline]
     pub fn mode(mut self, mode: GameMode) -> Self {
-        self.mode.replace(mode as u8);
+        self.mode.replace(mode.as_ruleset_id());
 
         self
     }
@@ -944,21 +1089,33 @@ poll_req!(GetBeatmapsetSearch => BeatmapsetSearchResult);
 pub struct GetScore<'a> {
     fut: Option<Pending<'a, Score>>,
     osu: &'a Osu,
-    mode: GameMode,
+    mode: Option<GameMode>,
     score_id: u64,
 }
 
 impl<'a> GetScore<'a> {
     #[inline]
-    pub(crate) fn new(osu: &'a Osu, score_id: u64, mode: GameMode) -> Self {
+    pub(crate) fn new(osu: &'a Osu, score_id: u64) -> Self {
         Self {
             fut: None,
             osu,
-            mode,
+            mode: None,
             score_id,
         }
     }
 
+    /// Specify the mode of the score.
+    ///
+    /// If no mode is specified, the score id is considered to be
+    /// mode-agnostic, i.e. the id of a score of the new scoring system.
+    /// Scores of the old system can only be requested by specifying their mode.
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Score> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.score.inc();
@@ -0,0 +1,160 @@
+use crate::{
+    model::changelog_::{ChangelogBuild, ChangelogListing},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+/// Get a [`ChangelogListing`](crate::model::changelog::ChangelogListing).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetChangelog<'a> {
+    fut: Option<Pending<'a, ChangelogListing>>,
+    osu: &'a Osu,
+    stream: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    max_id: Option<u32>,
+}
+
+impl<'a> GetChangelog<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            stream: None,
+            from: None,
+            to: None,
+            max_id: None,
+        }
+    }
+
+    /// Only consider builds of the given update stream, e.g. `stable40` or `lazer`
+    #[inline]
+    pub fn stream(mut self, stream: impl Into<String>) -> Self {
+        self.stream.replace(stream.into());
+
+        self
+    }
+
+    /// Only consider builds starting from the given version
+    #[inline]
+    pub fn from(mut self, version: impl Into<String>) -> Self {
+        self.from.replace(version.into());
+
+        self
+    }
+
+    /// Only consider builds up to the given version
+    #[inline]
+    pub fn to(mut self, version: impl Into<String>) -> Self {
+        self.to.replace(version.into());
+
+        self
+    }
+
+    /// Only consider builds whose id is at most the given one
+    #[inline]
+    pub fn max_id(mut self, max_id: u32) -> Self {
+        self.max_id.replace(max_id);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, ChangelogListing> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.changelog.inc();
+
+        let mut query = Query::new();
+
+        if let Some(stream) = self.stream.take() {
+            query.push("stream", stream);
+        }
+
+        if let Some(from) = self.from.take() {
+            query.push("from", from);
+        }
+
+        if let Some(to) = self.to.take() {
+            query.push("to", to);
+        }
+
+        if let Some(max_id) = self.max_id {
+            query.push("max_id", max_id);
+        }
+
+        let req = Request::with_query(Route::GetChangelog, query);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetChangelog => ChangelogListing);
+
+/// Get a single [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)
+/// including its `changelog_entries`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetChangelogBuild<'a> {
+    fut: Option<Pending<'a, ChangelogBuild>>,
+    osu: &'a Osu,
+    build: Option<ChangelogBuildId>,
+}
+
+enum ChangelogBuildId {
+    Version { stream: String, version: String },
+    Id(u32),
+}
+
+impl<'a> GetChangelogBuild<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, stream: impl Into<String>, version: impl Into<String>) -> Self {
+        let build = ChangelogBuildId::Version {
+            stream: stream.into(),
+            version: version.into(),
+        };
+
+        Self {
+            fut: None,
+            osu,
+            build: Some(build),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn by_id(osu: &'a Osu, build_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            build: Some(ChangelogBuildId::Id(build_id)),
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, ChangelogBuild> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.changelog_build.inc();
+
+        let req = match self.build.take().unwrap() {
+            ChangelogBuildId::Version { stream, version } => {
+                Request::new(Route::GetChangelogBuild {
+                    stream: Some(stream),
+                    build: version,
+                })
+            }
+            ChangelogBuildId::Id(build_id) => {
+                let mut query = Query::new();
+                query.push("key", "id");
+
+                let route = Route::GetChangelogBuild {
+                    stream: None,
+                    build: build_id.to_string(),
+                };
+
+                Request::with_query(route, query)
+            }
+        };
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetChangelogBuild => ChangelogBuild);
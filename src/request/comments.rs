@@ -8,6 +8,8 @@ use crate::{
     Osu,
 };
 
+use futures::future::TryFutureExt;
+
 /// Get a list of comments and their replies up to two levels deep
 /// in form of a [`CommentBundle`](crate::model::comments::CommentBundle).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -35,28 +37,30 @@ impl<'a> GetComments<'a> {
         }
     }
 
-    /// Sort the result by date, newest first
+    /// Specify the order of the comments
     #[inline]
-    pub fn sort_new(mut self) -> Self {
-        self.sort.replace(CommentSort::New);
+    pub fn sort(mut self, sort: CommentSort) -> Self {
+        self.sort.replace(sort);
 
         self
     }
 
-    /// Sort the result by vote count
+    /// Sort the result by date, newest first
     #[inline]
-    pub fn sort_top(mut self) -> Self {
-        self.sort.replace(CommentSort::Top);
+    pub fn sort_new(self) -> Self {
+        self.sort(CommentSort::New)
+    }
 
-        self
+    /// Sort the result by vote count
+    #[inline]
+    pub fn sort_top(self) -> Self {
+        self.sort(CommentSort::Top)
     }
 
     /// Sort the result by date, oldest first
     #[inline]
-    pub fn sort_old(mut self) -> Self {
-        self.sort.replace(CommentSort::Old);
-
-        self
+    pub fn sort_old(self) -> Self {
+        self.sort(CommentSort::Old)
     }
 
     /// Limit to comments which are reply to the specified id. Specify 0 to get top level comments
@@ -97,7 +101,7 @@ impl<'a> GetComments<'a> {
         let mut query = Query::new();
 
         if let Some(sort) = self.sort {
-            query.push("sort", &sort.to_string());
+            query.push("sort", sort.to_string());
         }
 
         if let Some(parent) = self.parent_id {
@@ -108,8 +112,8 @@ impl<'a> GetComments<'a> {
             query.push("commentable_id", commentable);
         }
 
-        if let Some(commentable) = self.commentable_type.take() {
-            query.push("commentable_type", &commentable);
+        if let Some(ref commentable) = self.commentable_type {
+            query.push("commentable_type", commentable);
         }
 
         if let Some(cursor) = self.cursor.take() {
@@ -118,7 +122,22 @@ impl<'a> GetComments<'a> {
 
         let req = Request::with_query(Route::GetComments, query);
 
-        Box::pin(self.osu.request(req))
+        let commentable_type = self.commentable_type.take();
+        let commentable_id = self.commentable_id;
+        let parent_id = self.parent_id;
+
+        let fut = self
+            .osu
+            .request::<CommentBundle>(req)
+            .map_ok(move |mut bundle| {
+                bundle.commentable_type = commentable_type;
+                bundle.commentable_id = commentable_id;
+                bundle.parent_id = parent_id;
+
+                bundle
+            });
+
+        Box::pin(fut)
     }
 }
 
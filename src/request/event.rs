@@ -0,0 +1,80 @@
+use crate::{
+    model::{recent_event_::Events, Cursor},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+use futures::future::TryFutureExt;
+
+/// Get [`Events`](crate::model::recent_event::Events) of the site-wide event feed.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetEvents<'a> {
+    fut: Option<Pending<'a, Events>>,
+    osu: &'a Osu,
+    sort: Option<&'static str>,
+    cursor: Option<Cursor>,
+}
+
+impl<'a> GetEvents<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            sort: None,
+            cursor: None,
+        }
+    }
+
+    /// Sort by ascending event ids i.e. oldest first
+    #[inline]
+    pub fn sort_ascending(mut self) -> Self {
+        self.sort.replace("id_asc");
+
+        self
+    }
+
+    /// Sort by descending event ids i.e. newest first. This is the default.
+    #[inline]
+    pub fn sort_descending(mut self) -> Self {
+        self.sort.replace("id_desc");
+
+        self
+    }
+
+    #[inline]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Events> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.events.inc();
+
+        let mut query = Query::new();
+
+        if let Some(sort) = self.sort {
+            query.push("sort", sort);
+        }
+
+        if let Some(cursor) = self.cursor.take() {
+            cursor.push_to_query(&mut query);
+        }
+
+        let sort = self.sort;
+        let req = Request::with_query(Route::GetEvents, query);
+
+        let fut = self.osu.request::<Events>(req).map_ok(move |mut events| {
+            events.sort = sort;
+
+            events
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetEvents => Events);
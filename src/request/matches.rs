@@ -56,9 +56,11 @@ impl<'a> GetMatch<'a> {
     }
 
     /// Get the match state after at most `limit` many new events.
+    ///
+    /// The API provides at most 100 events per request so larger values will be clamped to 100.
     #[inline]
     pub fn limit(mut self, limit: usize) -> Self {
-        self.limit.replace(limit);
+        self.limit.replace(limit.min(100));
 
         self
     }
@@ -108,6 +110,8 @@ poll_req!(GetMatch => OsuMatch);
 pub struct GetMatches<'a> {
     fut: Option<Pending<'a, MatchList>>,
     osu: &'a Osu,
+    limit: Option<u32>,
+    sort: Option<&'static str>,
     cursor: Option<Cursor>,
 }
 
@@ -117,10 +121,36 @@ impl<'a> GetMatches<'a> {
         Self {
             fut: None,
             osu,
+            limit: None,
+            sort: None,
             cursor: None,
         }
     }
 
+    /// Maximum number of matches to be returned (50 default, 50 at most)
+    #[inline]
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit.replace(limit.min(50));
+
+        self
+    }
+
+    /// Sort by ascending match ids i.e. oldest first
+    #[inline]
+    pub fn sort_ascending(mut self) -> Self {
+        self.sort.replace("id_asc");
+
+        self
+    }
+
+    /// Sort by descending match ids i.e. newest first. This is the default.
+    #[inline]
+    pub fn sort_descending(mut self) -> Self {
+        self.sort.replace("id_desc");
+
+        self
+    }
+
     #[inline]
     pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
         self.cursor.replace(cursor);
@@ -134,6 +164,14 @@ impl<'a> GetMatches<'a> {
 
         let mut query = Query::new();
 
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        if let Some(sort) = self.sort {
+            query.push("sort", sort);
+        }
+
         if let Some(cursor) = self.cursor.take() {
             cursor.push_to_query(&mut query);
         }
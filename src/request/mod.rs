@@ -21,9 +21,12 @@ macro_rules! poll_req {
 }
 
 mod beatmap;
+mod changelog;
 mod comments;
+mod event;
 mod forum;
 mod matches;
+mod multiplayer;
 mod news;
 mod ranking;
 mod replay;
@@ -32,9 +35,12 @@ mod user;
 mod wiki;
 
 pub use beatmap::*;
+pub use changelog::*;
 pub use comments::*;
+pub use event::*;
 pub use forum::*;
 pub use matches::*;
+pub use multiplayer::*;
 pub use news::*;
 pub use ranking::*;
 pub use replay::*;
@@ -50,6 +56,7 @@ use std::{
     fmt::{Display, Formatter, Result, Write},
     future::Future,
     pin::Pin,
+    time::Duration,
 };
 
 type Pending<'a, T> = Pin<Box<dyn Future<Output = OsuResult<T>> + Send + Sync + 'a>>;
@@ -60,6 +67,8 @@ pub(crate) struct Request {
     pub method: Method,
     pub path: Cow<'static, str>,
     pub body: Body,
+    /// Overrides the client's timeout, see [`OsuBuilder::timeout`](crate::OsuBuilder::timeout)
+    pub timeout: Option<Duration>,
 }
 
 impl Request {
@@ -83,6 +92,7 @@ impl Request {
             method,
             path,
             body,
+            timeout: None,
         }
     }
 }
@@ -143,6 +153,21 @@ impl Query {
         let _ = write!(self.query, "{}", value);
         self.query.push('&');
     }
+
+    /// Push each value with the key in the PHP-style array encoding
+    /// that the API expects, i.e. `key[]=a&key[]=b`.
+    pub(crate) fn push_all<I>(&mut self, key: &str, values: I)
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        for value in values {
+            self.query.push_str(key);
+            self.query.push_str("[]=");
+            let _ = write!(self.query, "{}", value);
+            self.query.push('&');
+        }
+    }
 }
 
 impl Display for Query {
@@ -156,3 +181,30 @@ impl Display for Query {
         f.write_str(&self.query[..self.query.len() - 1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_push_all() {
+        let mut query = Query::new();
+        query.push("mode", "osu");
+        query.push_all("ids", [1, 2, 3]);
+        query.push_all("mods", Vec::<&str>::new());
+        query.push_all("mods", ["HD", "HR"]);
+
+        assert_eq!(
+            query.to_string(),
+            "?mode=osu&ids[]=1&ids[]=2&ids[]=3&mods[]=HD&mods[]=HR"
+        );
+    }
+
+    #[test]
+    fn query_push_all_empty() {
+        let mut query = Query::new();
+        query.push_all("ids", Vec::<u32>::new());
+
+        assert_eq!(query.to_string(), "");
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    model::{
+        multiplayer_::{
+            MultiplayerScore, MultiplayerScores, MultiplayerScoresSort, Room, RoomMode, RoomStatus,
+        },
+        Cursor,
+    },
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+use futures::future::TryFutureExt;
+
+/// Get a [`MultiplayerScore`](crate::model::multiplayer::MultiplayerScore)
+/// on a playlist item in a multiplayer room.
+///
+/// The score is identified through the id of the room, the id of the
+/// room's playlist item, and the id of the score itself.
+///
+/// # Example
+///
+/// ```no_run
+/// # use rosu_v2::Osu;
+/// # async fn example(osu: &Osu) -> rosu_v2::OsuResult<()> {
+/// let (room, playlist, score_id) = (123, 456, 789);
+/// let score = osu.multiplayer_score(room, playlist, score_id).await?;
+///
+/// println!("{} scored {}", score.user_id, score.score);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetMultiplayerScore<'a> {
+    fut: Option<Pending<'a, MultiplayerScore>>,
+    osu: &'a Osu,
+    room_id: u32,
+    playlist_id: u32,
+    score_id: u64,
+}
+
+impl<'a> GetMultiplayerScore<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, room_id: u32, playlist_id: u32, score_id: u64) -> Self {
+        Self {
+            fut: None,
+            osu,
+            room_id,
+            playlist_id,
+            score_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, MultiplayerScore> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.multiplayer_score.inc();
+
+        let route = Route::GetMultiplayerScore {
+            room_id: self.room_id,
+            playlist_id: self.playlist_id,
+            score_id: self.score_id,
+        };
+
+        let req = Request::new(route);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetMultiplayerScore => MultiplayerScore);
+
+/// Get [`MultiplayerScores`](crate::model::multiplayer::MultiplayerScores)
+/// of a playlist item in a multiplayer room.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetMultiplayerScores<'a> {
+    fut: Option<Pending<'a, MultiplayerScores>>,
+    osu: &'a Osu,
+    room_id: u32,
+    playlist_id: u32,
+    limit: Option<u32>,
+    sort: Option<MultiplayerScoresSort>,
+    cursor: Option<Cursor>,
+}
+
+impl<'a> GetMultiplayerScores<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, room_id: u32, playlist_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            room_id,
+            playlist_id,
+            limit: None,
+            sort: None,
+            cursor: None,
+        }
+    }
+
+    /// Maximum number of scores to be returned.
+    ///
+    /// The API allows at most 100 scores so larger values will be clamped to 100.
+    #[inline]
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit.replace(limit.min(100));
+
+        self
+    }
+
+    /// Specify the order of the scores
+    #[inline]
+    pub fn sort(mut self, sort: MultiplayerScoresSort) -> Self {
+        self.sort.replace(sort);
+
+        self
+    }
+
+    /// Sort the scores by score, lowest first
+    #[inline]
+    pub fn sort_ascending(self) -> Self {
+        self.sort(MultiplayerScoresSort::ScoreAscending)
+    }
+
+    /// Sort the scores by score, highest first
+    #[inline]
+    pub fn sort_descending(self) -> Self {
+        self.sort(MultiplayerScoresSort::ScoreDescending)
+    }
+
+    #[inline]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, MultiplayerScores> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.multiplayer_scores.inc();
+
+        let mut query = Query::new();
+
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        if let Some(ref sort) = self.sort {
+            query.push("sort", sort);
+        }
+
+        if let Some(cursor) = self.cursor.take() {
+            cursor.push_to_query(&mut query);
+        }
+
+        let room_id = self.room_id;
+        let playlist_id = self.playlist_id;
+
+        let route = Route::GetMultiplayerScores {
+            room_id,
+            playlist_id,
+        };
+
+        let req = Request::with_query(route, query);
+
+        let fut = self
+            .osu
+            .request::<MultiplayerScores>(req)
+            .map_ok(move |mut scores| {
+                scores.room_id = room_id;
+                scores.playlist_id = playlist_id;
+
+                scores
+            });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetMultiplayerScores => MultiplayerScores);
+
+/// Get a multiplayer [`Room`](crate::model::multiplayer::Room) including its playlist
+/// and participant count.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetRoom<'a> {
+    fut: Option<Pending<'a, Room>>,
+    osu: &'a Osu,
+    room_id: u32,
+}
+
+impl<'a> GetRoom<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, room_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            room_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, Room> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.room.inc();
+
+        let req = Request::new(Route::GetRoom {
+            room_id: self.room_id,
+        });
+
+        let osu = self.osu;
+        let fut = osu.request::<Room>(req);
+
+        #[cfg(feature = "cache")]
+        let fut =
+            fut.inspect_ok(move |room| osu.update_cache(room.host.user_id, &room.host.username));
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetRoom => Room);
+
+/// Get a vec of multiplayer [`Room`](crate::model::multiplayer::Room)s.
+///
+/// The playlist of each room only contains its current item.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetRooms<'a> {
+    fut: Option<Pending<'a, Vec<Room>>>,
+    osu: &'a Osu,
+    mode: Option<RoomMode>,
+    status: Option<RoomStatus>,
+}
+
+impl<'a> GetRooms<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mode: None,
+            status: None,
+        }
+    }
+
+    /// Only consider rooms of the given kind.
+    /// If not specified, the API returns playlist rooms.
+    #[inline]
+    pub fn mode(mut self, mode: RoomMode) -> Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
+    /// Only consider rooms with the given status.
+    /// If not specified, the API returns active rooms.
+    #[inline]
+    pub fn status(mut self, status: RoomStatus) -> Self {
+        self.status.replace(status);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<Room>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.rooms.inc();
+
+        let mut query = Query::new();
+
+        if let Some(mode) = self.mode {
+            query.push("type_group", mode);
+        }
+
+        if let Some(status) = self.status {
+            query.push("mode", status);
+        }
+
+        let req = Request::with_query(Route::GetRooms, query);
+        let osu = self.osu;
+        let fut = osu.request::<Vec<Room>>(req);
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |rooms| {
+            for room in rooms {
+                osu.update_cache(room.host.user_id, &room.host.username);
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetRooms => Vec<Room>);
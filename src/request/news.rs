@@ -1,16 +1,26 @@
 use crate::{
-    model::{news_::News, Cursor},
+    error::OsuError,
+    model::{
+        news_::{News, NewsPost},
+        Cursor,
+    },
     request::{Pending, Query, Request},
     routing::Route,
     Osu,
 };
 
+use futures::future::{self, TryFutureExt};
+use time::OffsetDateTime;
+
+/// The year of the first news post
+const FIRST_NEWS_YEAR: u32 = 2013;
+
 /// Get a [`News`](crate::model::news::News) struct.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetNews<'a> {
     fut: Option<Pending<'a, News>>,
     osu: &'a Osu,
-    news: Option<()>, // TODO
+    year: Option<u32>,
     cursor: Option<Cursor>,
 }
 
@@ -20,21 +30,28 @@ impl<'a> GetNews<'a> {
         Self {
             fut: None,
             osu,
-            news: None,
+            year: None,
             cursor: None,
         }
     }
 
-    // TODO
-    // #[inline]
-    // pub fn news(mut self, news: ()) -> Self {
-    //     self.news.replace(news);
+    /// Only consider news posts of the given year.
+    ///
+    /// Available years are listed in [`NewsSidebar::years`](crate::model::news::NewsSidebar::years).
+    /// Years before the first news post in 2013 or after the current year are rejected
+    /// with [`OsuError::InvalidParameter`](crate::error::OsuError::InvalidParameter)
+    /// without sending a request.
+    #[inline]
+    pub fn year(mut self, year: u32) -> Self {
+        self.year.replace(year);
 
-    //     self
-    // }
+        self
+    }
 
+    /// Continue from the page of the given cursor, e.g. one that was
+    /// stored from [`News::cursor`](crate::model::news::News::cursor).
     #[inline]
-    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
         self.cursor.replace(cursor);
 
         self
@@ -46,14 +63,68 @@ impl<'a> GetNews<'a> {
 
         let mut query = Query::new();
 
+        if let Some(year) = self.year {
+            let current_year = OffsetDateTime::now_utc().year() as u32;
+
+            if !(FIRST_NEWS_YEAR..=current_year).contains(&year) {
+                let err = OsuError::InvalidParameter {
+                    param: "year",
+                    reason: format!("must be between {FIRST_NEWS_YEAR} and {current_year}"),
+                };
+
+                return Box::pin(future::ready(Err(err)));
+            }
+
+            query.push("year", year);
+        }
+
         if let Some(cursor) = self.cursor.take() {
             cursor.push_to_query(&mut query);
         }
 
-        let req = Request::with_query(Route::GetNews { news: self.news }, query);
+        let year = self.year;
+        let req = Request::with_query(Route::GetNews, query);
 
-        Box::pin(self.osu.request(req))
+        let fut = self.osu.request::<News>(req).map_ok(move |mut news| {
+            news.year = year;
+
+            news
+        });
+
+        Box::pin(fut)
     }
 }
 
 poll_req!(GetNews => News);
+
+/// Get a single [`NewsPost`](crate::model::news::NewsPost) including its full `content`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetNewsPost<'a> {
+    fut: Option<Pending<'a, NewsPost>>,
+    osu: &'a Osu,
+    slug: Option<String>,
+}
+
+impl<'a> GetNewsPost<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, slug: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            slug: Some(slug.into()),
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, NewsPost> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.news_post.inc();
+
+        let req = Request::new(Route::GetNewsPost {
+            news: self.slug.take().unwrap(),
+        });
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetNewsPost => NewsPost);
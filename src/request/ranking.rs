@@ -1,16 +1,23 @@
 use crate::{
+    error::OsuError,
     model::{
-        ranking_::{ChartRankings, CountryRankings, RankingType, Rankings, Spotlight},
-        user_::CountryCode,
-        GameMode,
+        ranking_::{
+            ChartRankings, CountryRankings, ManiaVariant, RankingType, Rankings, Spotlight,
+        },
+        user_::{CountryCode, UserCompact},
+        Cursor, GameMode,
     },
     request::{Pending, Query, Request},
     routing::Route,
     Osu,
 };
 
-use futures::future::TryFutureExt;
+use futures::future::{self, TryFutureExt};
 use serde::Deserialize;
+use std::time::Duration;
+
+const RANKING_PAGE_SIZE: u32 = 50;
+const MAX_RANKING_RANK: u32 = 200 * RANKING_PAGE_SIZE;
 
 /// Get a [`ChartRankings`](crate::model::ranking::ChartRankings) struct
 /// containing a [`Spotlight`](crate::model::ranking::Spotlight), its
@@ -29,6 +36,7 @@ pub struct GetChartRankings<'a> {
     osu: &'a Osu,
     mode: GameMode,
     spotlight: Option<u32>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetChartRankings<'a> {
@@ -39,6 +47,7 @@ impl<'a> GetChartRankings<'a> {
             osu,
             mode,
             spotlight: None,
+            timeout: None,
         }
     }
 
@@ -51,6 +60,18 @@ impl<'a> GetChartRankings<'a> {
         self
     }
 
+    /// Override the client's timeout for this request,
+    /// see [`OsuBuilder::timeout`](crate::OsuBuilder::timeout).
+    ///
+    /// Rankings can take a while to respond so a longer or shorter
+    /// timeout than for other requests may be desired.
+    #[inline]
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout.replace(duration);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, ChartRankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.chart_rankings.inc();
@@ -66,7 +87,8 @@ impl<'a> GetChartRankings<'a> {
             ranking_type: RankingType::Charts,
         };
 
-        let req = Request::with_query(route, query);
+        let mut req = Request::with_query(route, query);
+        req.timeout = self.timeout;
         let osu = self.osu;
         let fut = osu.request::<ChartRankings>(req);
 
@@ -92,6 +114,8 @@ pub struct GetCountryRankings<'a> {
     osu: &'a Osu,
     mode: GameMode,
     page: Option<u32>,
+    cursor: Option<Cursor>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetCountryRankings<'a> {
@@ -102,6 +126,8 @@ impl<'a> GetCountryRankings<'a> {
             osu,
             mode,
             page: None,
+            cursor: None,
+            timeout: None,
         }
     }
 
@@ -113,6 +139,24 @@ impl<'a> GetCountryRankings<'a> {
         self
     }
 
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    /// Override the client's timeout for this request.
+    ///
+    /// See [`GetChartRankings::timeout`].
+    #[inline]
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout.replace(duration);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, CountryRankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.country_rankings.inc();
@@ -121,21 +165,97 @@ impl<'a> GetCountryRankings<'a> {
 
         if let Some(page) = self.page {
             query.push("cursor[page]", page);
+        } else if let Some(ref cursor) = self.cursor {
+            cursor.push_to_query(&mut query);
         }
 
+        let mode = self.mode;
+
         let route = Route::GetRankings {
-            mode: self.mode,
+            mode,
             ranking_type: RankingType::Country,
         };
 
-        let req = Request::with_query(route, query);
+        let mut req = Request::with_query(route, query);
+        req.timeout = self.timeout;
 
-        Box::pin(self.osu.request(req))
+        let fut = self
+            .osu
+            .request::<CountryRankings>(req)
+            .map_ok(move |mut rankings| {
+                rankings.mode.replace(mode);
+
+                rankings
+            });
+
+        Box::pin(fut)
     }
 }
 
 poll_req!(GetCountryRankings => CountryRankings);
 
+/// Get a vec of [`UserCompact`](crate::model::user::UserCompact) sorted
+/// by their total kudosu, i.e. the current kudosu leaderboard.
+///
+/// The `kudosu` option of the users will be filled.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetKudosuRankings<'a> {
+    fut: Option<Pending<'a, Vec<UserCompact>>>,
+    osu: &'a Osu,
+    page: Option<u32>,
+}
+
+impl<'a> GetKudosuRankings<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            page: None,
+        }
+    }
+
+    /// Pages range from 1 to 20, each containing 50 users.
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<UserCompact>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.kudosu_rankings.inc();
+
+        let mut query = Query::new();
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        let req = Request::with_query(Route::GetKudosuRankings, query);
+        let osu = self.osu;
+
+        let fut = osu.request::<KudosuRankings>(req).map_ok(move |rankings| {
+            #[cfg(feature = "cache")]
+            for user in rankings.ranking.iter() {
+                osu.update_cache(user.user_id, &user.username);
+            }
+
+            rankings.ranking
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetKudosuRankings => Vec<UserCompact>);
+
+#[derive(Deserialize)]
+struct KudosuRankings {
+    ranking: Vec<UserCompact>,
+}
+
 /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
 /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
 /// by their pp, i.e. the current pp leaderboard.
@@ -145,8 +265,11 @@ pub struct GetPerformanceRankings<'a> {
     osu: &'a Osu,
     mode: GameMode,
     country: Option<CountryCode>,
-    variant: Option<&'static str>,
+    variant: Option<ManiaVariant>,
     page: Option<u32>,
+    cursor: Option<Cursor>,
+    limit: Option<usize>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetPerformanceRankings<'a> {
@@ -159,6 +282,9 @@ impl<'a> GetPerformanceRankings<'a> {
             country: None,
             variant: None,
             page: None,
+            cursor: None,
+            limit: None,
+            timeout: None,
         }
     }
 
@@ -170,20 +296,28 @@ impl<'a> GetPerformanceRankings<'a> {
         self
     }
 
-    /// Consider only 4K scores. Only relevant for osu!mania.
+    /// Consider only scores of the given keymode variant.
+    ///
+    /// Only available for osu!mania; for other modes the request fails with
+    /// [`OsuError::InvalidParameter`](crate::error::OsuError::InvalidParameter)
+    /// without being sent.
     #[inline]
-    pub fn variant_4k(mut self) -> Self {
-        self.variant.replace("4k");
+    pub fn variant(mut self, variant: ManiaVariant) -> Self {
+        self.variant.replace(variant);
 
         self
     }
 
-    /// Consider only 7K scores. Only relevant for osu!mania.
+    /// Consider only 4K scores. Only available for osu!mania, see [`variant`](Self::variant).
     #[inline]
-    pub fn variant_7k(mut self) -> Self {
-        self.variant.replace("7k");
+    pub fn variant_4k(self) -> Self {
+        self.variant(ManiaVariant::FourKeys)
+    }
 
-        self
+    /// Consider only 7K scores. Only available for osu!mania, see [`variant`](Self::variant).
+    #[inline]
+    pub fn variant_7k(self) -> Self {
+        self.variant(ManiaVariant::SevenKeys)
     }
 
     /// Pages range from 1 to 200.
@@ -194,24 +328,78 @@ impl<'a> GetPerformanceRankings<'a> {
         self
     }
 
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    /// Only keep the first `limit` users of the requested page.
+    ///
+    /// The API does not support a page size for rankings so the full page
+    /// is still requested and truncated afterwards. This does not reduce
+    /// bandwidth, only the amount of users that are kept.
+    ///
+    /// **Note:** The limit breaks pagination. [`Rankings::get_next`] and
+    /// [`Rankings::into_stream`] continue with the next full page, so the
+    /// users that were truncated from this page are silently skipped.
+    /// Don't set a limit if you want to paginate through the rankings.
+    ///
+    /// [`Rankings::get_next`]: crate::model::ranking::Rankings::get_next
+    /// [`Rankings::into_stream`]: crate::model::ranking::Rankings::into_stream
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit);
+
+        self
+    }
+
+    /// Override the client's timeout for this request.
+    ///
+    /// See [`GetChartRankings::timeout`].
+    #[inline]
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout.replace(duration);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Rankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.performance_rankings.inc();
 
         let mode = self.mode;
+
+        if self.variant.is_some() && mode != GameMode::Mania {
+            let err = OsuError::InvalidParameter {
+                param: "variant",
+                reason: format!("only available for osu!mania, not {:?}", mode),
+            };
+
+            return Box::pin(future::ready(Err(err)));
+        }
+
         let mut query = Query::new();
 
         if let Some(ref country) = self.country {
             query.push("country", country);
         }
 
-        // ! Adjust filter once there are non-mania variants
-        if let Some(variant) = self.variant.filter(|_| mode == GameMode::Mania) {
+        #[cfg(not(feature = "rkyv"))]
+        let country = self.country.take();
+
+        let variant = self.variant;
+
+        if let Some(variant) = variant {
             query.push("variant", variant);
         }
 
         if let Some(page) = self.page {
             query.push("cursor[page]", page);
+        } else if let Some(ref cursor) = self.cursor {
+            cursor.push_to_query(&mut query);
         }
 
         let route = Route::GetRankings {
@@ -219,8 +407,10 @@ impl<'a> GetPerformanceRankings<'a> {
             ranking_type: RankingType::Performance,
         };
 
-        let req = Request::with_query(route, query);
+        let mut req = Request::with_query(route, query);
+        req.timeout = self.timeout;
         let osu = self.osu;
+        let limit = self.limit;
 
         let fut = osu
             .request::<Rankings>(req)
@@ -228,13 +418,21 @@ impl<'a> GetPerformanceRankings<'a> {
                 rankings.mode.replace(mode);
 
                 #[cfg(not(feature = "rkyv"))]
-                rankings.ranking_type.replace(RankingType::Performance);
+                {
+                    rankings.country = country;
+                    rankings.ranking_type.replace(RankingType::Performance);
+                    rankings.variant = variant;
+                }
 
                 #[cfg(feature = "cache")]
                 for user in rankings.ranking.iter() {
                     osu.update_cache(user.user_id, &user.username);
                 }
 
+                if let Some(limit) = limit {
+                    rankings.ranking.truncate(limit);
+                }
+
                 rankings
             });
 
@@ -244,6 +442,83 @@ impl<'a> GetPerformanceRankings<'a> {
 
 poll_req!(GetPerformanceRankings => Rankings);
 
+/// Get the [`UserCompact`](crate::model::user::UserCompact) at a specific
+/// position of the pp leaderboard.
+///
+/// Only the page containing the rank is requested.
+/// The API only provides the top 10,000 users so ranks of 0 or above 10,000
+/// fail with [`OsuError::InvalidParameter`](crate::error::OsuError::InvalidParameter)
+/// without sending a request. The same error is returned if the rank
+/// exceeds the amount of ranked users.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetUserAtRank<'a> {
+    fut: Option<Pending<'a, UserCompact>>,
+    osu: &'a Osu,
+    mode: GameMode,
+    rank: u32,
+    country: Option<CountryCode>,
+}
+
+impl<'a> GetUserAtRank<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mode: GameMode, rank: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mode,
+            rank,
+            country: None,
+        }
+    }
+
+    /// Specify a country code to get the user at the given country rank.
+    #[inline]
+    pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.country.replace(country.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, UserCompact> {
+        let rank = self.rank;
+
+        if !(1..=MAX_RANKING_RANK).contains(&rank) {
+            let err = OsuError::InvalidParameter {
+                param: "rank",
+                reason: format!("must be between 1 and {MAX_RANKING_RANK}"),
+            };
+
+            return Box::pin(future::ready(Err(err)));
+        }
+
+        let page = (rank - 1) / RANKING_PAGE_SIZE + 1;
+        let idx = ((rank - 1) % RANKING_PAGE_SIZE) as usize;
+
+        let mut req = self.osu.performance_rankings(self.mode).page(page);
+
+        if let Some(country) = self.country.take() {
+            req = req.country(country);
+        }
+
+        let fut = req.and_then(move |mut rankings| {
+            let res = if idx < rankings.ranking.len() {
+                Ok(rankings.ranking.swap_remove(idx))
+            } else {
+                Err(OsuError::InvalidParameter {
+                    param: "rank",
+                    reason: format!("only {} users are ranked", rankings.total),
+                })
+            };
+
+            future::ready(res)
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetUserAtRank => UserCompact);
+
 /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
 /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
 /// by their ranked score, i.e. the current ranked score leaderboard.
@@ -253,6 +528,9 @@ pub struct GetScoreRankings<'a> {
     osu: &'a Osu,
     mode: GameMode,
     page: Option<u32>,
+    cursor: Option<Cursor>,
+    limit: Option<usize>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetScoreRankings<'a> {
@@ -263,6 +541,9 @@ impl<'a> GetScoreRankings<'a> {
             osu,
             mode,
             page: None,
+            cursor: None,
+            limit: None,
+            timeout: None,
         }
     }
 
@@ -274,6 +555,34 @@ impl<'a> GetScoreRankings<'a> {
         self
     }
 
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    /// Only keep the first `limit` users of the requested page.
+    ///
+    /// **Note:** The limit breaks pagination, see [`GetPerformanceRankings::limit`].
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit);
+
+        self
+    }
+
+    /// Override the client's timeout for this request.
+    ///
+    /// See [`GetChartRankings::timeout`].
+    #[inline]
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout.replace(duration);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Rankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.score_rankings.inc();
@@ -283,6 +592,8 @@ impl<'a> GetScoreRankings<'a> {
 
         if let Some(page) = self.page {
             query.push("cursor[page]", page);
+        } else if let Some(ref cursor) = self.cursor {
+            cursor.push_to_query(&mut query);
         }
 
         let route = Route::GetRankings {
@@ -290,8 +601,10 @@ impl<'a> GetScoreRankings<'a> {
             ranking_type: RankingType::Score,
         };
 
-        let req = Request::with_query(route, query);
+        let mut req = Request::with_query(route, query);
+        req.timeout = self.timeout;
         let osu = self.osu;
+        let limit = self.limit;
 
         let fut = osu
             .request::<Rankings>(req)
@@ -306,6 +619,10 @@ impl<'a> GetScoreRankings<'a> {
                     osu.update_cache(user.user_id, &user.username);
                 }
 
+                if let Some(limit) = limit {
+                    rankings.ranking.truncate(limit);
+                }
+
                 rankings
             });
 
@@ -316,6 +633,10 @@ impl<'a> GetScoreRankings<'a> {
 poll_req!(GetScoreRankings => Rankings);
 
 /// Get a vec of [`Spotlight`](crate::model::ranking::Spotlight)s.
+///
+/// The `spotlight_id` of a spotlight can be passed to
+/// [`GetChartRankings::spotlight`] to request the spotlight's
+/// [`ChartRankings`](crate::model::ranking::ChartRankings).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetSpotlights<'a> {
     fut: Option<Pending<'a, Vec<Spotlight>>>,
@@ -1,11 +1,12 @@
 use crate::{
+    client::Scope,
     error::OsuError,
     model::{
         beatmap::{Beatmapset, MostPlayedMap, RankStatus},
         kudosu_::KudosuHistory,
         recent_event_::RecentEvent,
         score_::Score,
-        user_::{User, UserCompact},
+        user_::{MapsetType, User, UserCompact, Users},
         GameMode,
     },
     prelude::Username,
@@ -14,11 +15,10 @@ use crate::{
     Osu,
 };
 
+use futures::future::{self, TryFutureExt};
+use hyper::StatusCode;
 use smallstr::SmallString;
-use std::fmt;
-
-#[cfg(feature = "cache")]
-use {futures::future::TryFutureExt, std::mem};
+use std::{collections::HashMap, fmt, mem};
 
 /// Either a user id as u32 or a username as String.
 ///
@@ -83,6 +83,10 @@ impl fmt::Display for UserId {
 /// through the OAuth process in order for this endpoint to not return an error.
 ///
 /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+/// If the client was not authorized by a user, the request fails with
+/// [`OsuError::UnavailableEndpoint`](crate::error::OsuError::UnavailableEndpoint) without being sent.
+/// If the scope was not specified through [`OsuBuilder::scopes`](crate::OsuBuilder::scopes),
+/// the request fails with [`OsuError::MissingScope`](crate::error::OsuError::MissingScope).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetOwnData<'a> {
     fut: Option<Pending<'a, User>>,
@@ -112,6 +116,14 @@ impl<'a> GetOwnData<'a> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.own_data.inc();
 
+        if !self.osu.is_user_authorized() {
+            return Box::pin(future::ready(Err(OsuError::UnavailableEndpoint)));
+        }
+
+        if let Err(err) = self.osu.check_scope(Scope::Identify) {
+            return Box::pin(future::ready(Err(err)));
+        }
+
         let req = Request::new(Route::GetOwnData { mode: self.mode });
         let osu = self.osu;
         let fut = osu.request::<User>(req);
@@ -125,7 +137,74 @@ impl<'a> GetOwnData<'a> {
 
 poll_req!(GetOwnData => User);
 
-/// Get a [`User`](crate::model::user::User) by their id.
+/// Get the friends of the authenticated user as a vec of
+/// [`UserCompact`](crate::model::user::UserCompact).
+///
+/// Note that the client has to be initialized with the `friends.read` scope
+/// through the OAuth process in order for this endpoint to not return an error.
+///
+/// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+/// If the client was not authorized by a user, the request fails with
+/// [`OsuError::UnavailableEndpoint`](crate::error::OsuError::UnavailableEndpoint).
+/// If the scope was not specified through [`OsuBuilder::scopes`](crate::OsuBuilder::scopes)
+/// or the API responds with a 403 because the token lacks the scope, the request fails with
+/// [`OsuError::MissingScope`](crate::error::OsuError::MissingScope).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetFriends<'a> {
+    fut: Option<Pending<'a, Vec<UserCompact>>>,
+    osu: &'a Osu,
+}
+
+impl<'a> GetFriends<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self { fut: None, osu }
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<UserCompact>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.friends.inc();
+
+        if !self.osu.is_user_authorized() {
+            return Box::pin(future::ready(Err(OsuError::UnavailableEndpoint)));
+        }
+
+        if let Err(err) = self.osu.check_scope(Scope::FriendsRead) {
+            return Box::pin(future::ready(Err(err)));
+        }
+
+        let req = Request::new(Route::GetFriends);
+        let osu = self.osu;
+
+        let fut = osu
+            .request::<Vec<UserCompact>>(req)
+            .map_err(|err| match err {
+                OsuError::Response { status, .. } if status == StatusCode::FORBIDDEN => {
+                    OsuError::MissingScope {
+                        scope: Scope::FriendsRead,
+                    }
+                }
+                err => err,
+            });
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |users| {
+            for user in users {
+                osu.update_cache(user.user_id, &user.username);
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetFriends => Vec<UserCompact>);
+
+/// Get a [`User`](crate::model::user::User) by their id or username.
+///
+/// The API is told explicitly whether the given [`UserId`] is an id or a
+/// username so that numeric usernames such as `"12345"` are not mistaken
+/// for the user with id `12345`.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetUser<'a> {
     fut: Option<Pending<'a, User>>,
@@ -145,7 +224,10 @@ impl<'a> GetUser<'a> {
         }
     }
 
-    /// Specify the mode for which the user data should be retrieved
+    /// Specify the mode for which the user data should be retrieved.
+    ///
+    /// The user's `statistics` and mode-specific data such as `rank_history`
+    /// will be of this mode. If not specified, the user's default mode is used.
     #[inline]
     pub fn mode(mut self, mode: GameMode) -> Self {
         self.mode.replace(mode);
@@ -189,6 +271,7 @@ poll_req!(GetUser => User);
 /// Get the [`Beatmapset`](crate::model::beatmap::Beatmapset)s of a user by their id.
 ///
 /// If no map type specified, either manually through
+/// [`map_type`](crate::request::GetUserBeatmapsets::map_type),
 /// [`status`](crate::request::GetUserBeatmapsets::status),
 /// or through any of the methods [`loved`](crate::request::GetUserBeatmapsets::loved),
 /// [`graveyard`](crate::request::GetUserBeatmapsets::graveyard),
@@ -199,7 +282,7 @@ poll_req!(GetUser => User);
 pub struct GetUserBeatmapsets<'a> {
     fut: Option<Pending<'a, Vec<Beatmapset>>>,
     osu: &'a Osu,
-    map_type: &'static str,
+    map_type: MapsetType,
     limit: Option<usize>,
     offset: Option<usize>,
 
@@ -218,7 +301,7 @@ impl<'a> GetUserBeatmapsets<'a> {
             fut: None,
             osu,
             user_id,
-            map_type: "ranked_and_approved",
+            map_type: MapsetType::Ranked,
             limit: None,
             offset: None,
         }
@@ -231,7 +314,7 @@ impl<'a> GetUserBeatmapsets<'a> {
             fut: None,
             osu,
             user_id,
-            map_type: "ranked",
+            map_type: MapsetType::Ranked,
             limit: None,
             offset: None,
         }
@@ -254,56 +337,56 @@ impl<'a> GetUserBeatmapsets<'a> {
         self
     }
 
+    /// Specify the category of mapsets
+    #[inline]
+    pub fn map_type(mut self, map_type: MapsetType) -> Self {
+        self.map_type = map_type;
+
+        self
+    }
+
     /// Only include mapsets with the specified status
     #[inline]
-    pub fn status(mut self, map_type: RankStatus) -> Self {
-        self.map_type = match map_type {
-            RankStatus::Approved | RankStatus::Ranked => "ranked",
-            RankStatus::Graveyard => "graveyard",
-            RankStatus::Pending | RankStatus::WIP | RankStatus::Qualified => "pending",
-            RankStatus::Loved => "loved",
+    pub fn status(self, map_type: RankStatus) -> Self {
+        let map_type = match map_type {
+            RankStatus::Approved | RankStatus::Ranked => MapsetType::Ranked,
+            RankStatus::Graveyard => MapsetType::Graveyard,
+            RankStatus::Pending | RankStatus::WIP | RankStatus::Qualified => MapsetType::Pending,
+            RankStatus::Loved => MapsetType::Loved,
         };
 
-        self
+        self.map_type(map_type)
     }
 
     /// Require mapset rank status to be `loved`
     #[inline]
-    pub fn loved(mut self) -> Self {
-        self.map_type = "loved";
-
-        self
+    pub fn loved(self) -> Self {
+        self.map_type(MapsetType::Loved)
     }
 
     /// Require mapset rank status to be `graveyard`
     #[inline]
-    pub fn graveyard(mut self) -> Self {
-        self.map_type = "graveyard";
-
-        self
+    pub fn graveyard(self) -> Self {
+        self.map_type(MapsetType::Graveyard)
     }
 
     /// Require mapset rank status to be either `ranked` or `approved`
     #[inline]
-    pub fn ranked(mut self) -> Self {
-        self.map_type = "ranked";
-
-        self
+    pub fn ranked(self) -> Self {
+        self.map_type(MapsetType::Ranked)
     }
 
     /// Require mapset rank status to be `pending`
     #[inline]
-    pub fn pending(mut self) -> Self {
-        self.map_type = "pending";
-
-        self
+    pub fn pending(self) -> Self {
+        self.map_type(MapsetType::Pending)
     }
 
     fn start(&mut self) -> Pending<'a, Vec<Beatmapset>> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.user_beatmapsets.inc();
 
-        let map_type = self.map_type;
+        let map_type = self.map_type.as_str();
         let mut query = Query::new();
 
         if let Some(limit) = self.limit {
@@ -674,6 +757,7 @@ impl fmt::Display for ScoreType {
 /// If no score type is specified by either
 /// [`best`](crate::request::GetUserScores::best),
 /// [`firsts`](crate::request::GetUserScores::firsts),
+/// [`pinned`](crate::request::GetUserScores::pinned),
 /// or [`recent`](crate::request::GetUserScores::recent), it defaults to `best`.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetUserScores<'a> {
@@ -682,7 +766,7 @@ pub struct GetUserScores<'a> {
     score_type: ScoreType,
     limit: Option<usize>,
     offset: Option<usize>,
-    include_fails: Option<bool>,
+    include_fails: bool,
     mode: Option<GameMode>,
 
     #[cfg(not(feature = "cache"))]
@@ -703,7 +787,7 @@ impl<'a> GetUserScores<'a> {
             score_type: ScoreType::Best,
             limit: None,
             offset: None,
-            include_fails: None,
+            include_fails: false,
             mode: None,
         }
     }
@@ -718,7 +802,7 @@ impl<'a> GetUserScores<'a> {
             score_type: ScoreType::Best,
             limit: None,
             offset: None,
-            include_fails: None,
+            include_fails: false,
             mode: None,
         }
     }
@@ -740,7 +824,12 @@ impl<'a> GetUserScores<'a> {
         self
     }
 
-    /// Specify the mode of the scores
+    /// Specify the mode of the scores.
+    ///
+    /// If not specified, the scores will be of the user's default mode.
+    /// Note that this does not convert scores between modes; the API only provides
+    /// scores that were set in the given mode, which includes plays on osu!standard
+    /// maps that were converted to osu!taiko, osu!catch, or osu!mania.
     #[inline]
     pub fn mode(mut self, mode: GameMode) -> Self {
         self.mode.replace(mode);
@@ -748,12 +837,14 @@ impl<'a> GetUserScores<'a> {
         self
     }
 
-    /// Specify whether failed scores can be included.
+    /// Specify whether failed scores can be included. Defaults to `false`.
     ///
-    /// Only relevant for [`recent`](GetUserScores::recent)
+    /// Only available for [`recent`](GetUserScores::recent) scores;
+    /// enabling it for any other score type results in an
+    /// [`OsuError::InvalidParameter`](crate::error::OsuError::InvalidParameter).
     #[inline]
     pub fn include_fails(mut self, include_fails: bool) -> Self {
-        self.include_fails.replace(include_fails);
+        self.include_fails = include_fails;
 
         self
     }
@@ -799,24 +890,16 @@ impl<'a> GetUserScores<'a> {
             ScoreType::Recent => self.osu.metrics.user_recent_scores.inc(),
         }
 
-        let mut query = Query::new();
-
-        if let Some(limit) = self.limit {
-            query.push("limit", limit);
-        }
-
-        if let Some(offset) = self.offset {
-            query.push("offset", offset);
-        }
-
-        if let Some(mode) = self.mode {
-            query.push("mode", mode.to_string());
-        }
+        if self.include_fails && !matches!(self.score_type, ScoreType::Recent) {
+            let err = OsuError::InvalidParameter {
+                param: "include_fails",
+                reason: format!("only available for recent scores, not {}", self.score_type),
+            };
 
-        if let Some(include_fails) = self.include_fails {
-            query.push("include_fails", include_fails as u8);
+            return Box::pin(future::ready(Err(err)));
         }
 
+        let query = user_scores_query(self.limit, self.offset, self.mode, self.include_fails);
         let osu = self.osu;
 
         #[cfg(not(feature = "cache"))]
@@ -855,28 +938,52 @@ impl<'a> GetUserScores<'a> {
 
 poll_req!(GetUserScores => Vec<Score>);
 
+fn user_scores_query(
+    limit: Option<usize>,
+    offset: Option<usize>,
+    mode: Option<GameMode>,
+    include_fails: bool,
+) -> Query {
+    let mut query = Query::new();
+
+    if let Some(limit) = limit {
+        query.push("limit", limit);
+    }
+
+    if let Some(offset) = offset {
+        query.push("offset", offset);
+    }
+
+    if let Some(mode) = mode {
+        query.push("mode", mode.to_string());
+    }
+
+    if include_fails {
+        query.push("include_fails", 1);
+    }
+
+    query
+}
+
 /// Get a vec of [`UserCompact`](crate::model::user::UserCompact) by their ids.
-#[allow(dead_code)]
+///
+/// At most 50 users can be requested at once, any further ids are ignored.
+/// The users are ordered the same way as the given ids.
+/// Ids of users that the API does not provide e.g. because they're restricted are skipped.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetUsers<'a> {
     fut: Option<Pending<'a, Vec<UserCompact>>>,
     osu: &'a Osu,
-    form: Option<Query>,
+    user_ids: Vec<u32>,
 }
 
 impl<'a> GetUsers<'a> {
     #[inline]
     pub(crate) fn new(osu: &'a Osu, user_ids: &[u32]) -> Self {
-        let mut query = Query::new();
-
-        for user_id in user_ids.iter().take(50) {
-            query.push("id[]", user_id);
-        }
-
         Self {
             fut: None,
             osu,
-            form: Some(query),
+            user_ids: user_ids.iter().copied().take(50).collect(),
         }
     }
 
@@ -884,14 +991,96 @@ impl<'a> GetUsers<'a> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.users.inc();
 
-        Box::pin(async { Err(OsuError::UnavailableEndpoint) })
+        let user_ids = mem::take(&mut self.user_ids);
+        let mut query = Query::new();
+
+        query.push_all("ids", &user_ids);
+
+        let req = Request::with_query(Route::GetUsers, query);
+        let osu = self.osu;
 
-        // let query = self.query.take().unwrap();
-        // let req = Request::from((query, Route::GetUsers));
+        let fut = osu
+            .request::<Users>(req)
+            .map_ok(move |Users { mut users }| {
+                users.sort_by_key(|user| {
+                    user_ids
+                        .iter()
+                        .position(|&user_id| user_id == user.user_id)
+                        .unwrap_or(usize::MAX)
+                });
 
-        // // TODO: cache users
-        // Box::pin(self.osu.request(req))
+                #[cfg(feature = "cache")]
+                for user in users.iter() {
+                    osu.update_cache(user.user_id, &user.username);
+                }
+
+                users
+            });
+
+        Box::pin(fut)
     }
 }
 
 poll_req!(GetUsers => Vec<UserCompact>);
+
+/// Get [`UserCompact`](crate::model::user::UserCompact)s mapped to their user ids.
+///
+/// Duplicate ids are only requested once and the ids are requested
+/// in chunks of 50, the maximum amount the API allows per request.
+/// Ids of users that the API does not provide e.g. because they're restricted are missing.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetUsersMap<'a> {
+    fut: Option<Pending<'a, HashMap<u32, UserCompact>>>,
+    osu: &'a Osu,
+    user_ids: Vec<u32>,
+}
+
+impl<'a> GetUsersMap<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, user_ids: &[u32]) -> Self {
+        let mut user_ids = user_ids.to_vec();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+
+        Self {
+            fut: None,
+            osu,
+            user_ids,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, HashMap<u32, UserCompact>> {
+        let user_ids = mem::take(&mut self.user_ids);
+        let osu = self.osu;
+
+        let fut = async move {
+            let mut users = HashMap::with_capacity(user_ids.len());
+
+            for chunk in user_ids.chunks(50) {
+                for user in osu.users(chunk).await? {
+                    users.insert(user.user_id, user);
+                }
+            }
+
+            Ok(users)
+        };
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetUsersMap => HashMap<u32, UserCompact>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_scores_include_fails() {
+        let query = user_scores_query(Some(5), None, None, true).to_string();
+        assert!(query.contains("include_fails=1"));
+
+        let query = user_scores_query(Some(5), None, None, false).to_string();
+        assert!(!query.contains("include_fails"));
+    }
+}
@@ -25,6 +25,16 @@ impl<'a> GetWikiPage<'a> {
         }
     }
 
+    /// Specify the locale, e.g. `en` for english, `de` for german, ...
+    ///
+    /// Overrides the locale that was given when creating the request.
+    #[inline]
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale.replace(locale.into());
+
+        self
+    }
+
     /// Specify the page
     #[inline]
     pub fn page(mut self, page: impl Into<String>) -> Self {
@@ -5,6 +5,7 @@ use crate::{
 
 use hyper::Method;
 use std::{borrow::Cow, fmt::Write};
+use url::form_urlencoded::byte_serialize;
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
@@ -32,15 +33,33 @@ pub(crate) enum Route {
     GetBeatmapsetFromMapId,
     GetBeatmapsetEvents,
     GetBeatmapsetSearch,
+    GetChangelog,
+    GetChangelogBuild {
+        stream: Option<String>,
+        build: String,
+    },
     GetComments,
+    GetEvents,
     GetForumPosts {
         topic_id: u64,
     },
+    GetFriends,
+    GetKudosuRankings,
     GetMatch {
         match_id: Option<u32>,
     },
-    GetNews {
-        news: Option<()>,
+    GetMultiplayerScore {
+        room_id: u32,
+        playlist_id: u32,
+        score_id: u64,
+    },
+    GetMultiplayerScores {
+        room_id: u32,
+        playlist_id: u32,
+    },
+    GetNews,
+    GetNewsPost {
+        news: String,
     },
     GetOwnData {
         mode: Option<GameMode>,
@@ -56,8 +75,12 @@ pub(crate) enum Route {
         mode: GameMode,
         score_id: u64,
     },
+    GetRoom {
+        room_id: u32,
+    },
+    GetRooms,
     GetScore {
-        mode: GameMode,
+        mode: Option<GameMode>,
         score_id: u64,
     },
     GetSeasonalBackgrounds,
@@ -77,7 +100,6 @@ pub(crate) enum Route {
         user_id: u32,
         score_type: ScoreType,
     },
-    #[allow(dead_code)]
     GetUsers,
     GetWikiPage {
         locale: String,
@@ -111,10 +133,28 @@ impl Route {
             Self::GetBeatmapsetFromMapId => (Method::GET, "beatmapsets/lookup".into()),
             Self::GetBeatmapsetEvents => (Method::GET, "beatmapsets/events".into()),
             Self::GetBeatmapsetSearch => (Method::GET, "beatmapsets/search".into()),
+            Self::GetChangelog => (Method::GET, "changelog".into()),
+            Self::GetChangelogBuild { stream, build } => {
+                let build: String = byte_serialize(build.as_bytes()).collect();
+
+                let path = match stream {
+                    Some(stream) => {
+                        let stream: String = byte_serialize(stream.as_bytes()).collect();
+
+                        format!("changelog/{}/{}", stream, build)
+                    }
+                    None => format!("changelog/{}", build),
+                };
+
+                (Method::GET, path.into())
+            }
             Self::GetComments => (Method::GET, "comments".into()),
+            Self::GetEvents => (Method::GET, "events".into()),
             Self::GetForumPosts { topic_id } => {
                 (Method::GET, format!("forums/topics/{}", topic_id).into())
             }
+            Self::GetFriends => (Method::GET, "friends".into()),
+            Self::GetKudosuRankings => (Method::GET, "rankings/kudosu".into()),
             Self::GetMatch { match_id } => {
                 let path = match match_id {
                     Some(id) => format!("matches/{}", id).into(),
@@ -123,13 +163,31 @@ impl Route {
 
                 (Method::GET, path)
             }
-            Self::GetNews { news } => {
-                let path = match news {
-                    Some(_news) => unimplemented!(),
-                    None => "news".into(),
-                };
+            Self::GetMultiplayerScore {
+                room_id,
+                playlist_id,
+                score_id,
+            } => (
+                Method::GET,
+                format!(
+                    "rooms/{}/playlist/{}/scores/{}",
+                    room_id, playlist_id, score_id
+                )
+                .into(),
+            ),
+            Self::GetMultiplayerScores {
+                room_id,
+                playlist_id,
+            } => (
+                Method::GET,
+                format!("rooms/{}/playlist/{}/scores", room_id, playlist_id).into(),
+            ),
+            Self::GetNews => (Method::GET, "news".into()),
+            Self::GetNewsPost { news } => {
+                // Slugs are user input so they need to be encoded to stay within the path
+                let news: String = byte_serialize(news.as_bytes()).collect();
 
-                (Method::GET, path)
+                (Method::GET, format!("news/{}", news).into())
             }
             Self::GetOwnData { mode } => {
                 let path = match mode {
@@ -151,9 +209,16 @@ impl Route {
                 Method::GET,
                 format!("scores/{}/{}/download", mode, score_id).into(),
             ),
-            Self::GetScore { mode, score_id } => {
-                (Method::GET, format!("scores/{}/{}", mode, score_id).into())
-            }
+            Self::GetRoom { room_id } => (Method::GET, format!("rooms/{}", room_id).into()),
+            Self::GetRooms => (Method::GET, "rooms".into()),
+            Self::GetScore {
+                mode: Some(mode),
+                score_id,
+            } => (Method::GET, format!("scores/{}/{}", mode, score_id).into()),
+            Self::GetScore {
+                mode: None,
+                score_id,
+            } => (Method::GET, format!("scores/{}", score_id).into()),
             Self::GetSeasonalBackgrounds => (Method::GET, "seasonal-backgrounds".into()),
             Self::GetSpotlights => (Method::GET, "spotlights".into()),
             Self::GetUser { user_id, mode } => {
@@ -0,0 +1,1247 @@
+extern crate rosu_v2;
+
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, HeaderMap, Method, Request, Response, Server, StatusCode,
+};
+use once_cell::sync::OnceCell;
+use rosu_v2::{
+    prelude::{BeatmapsetEventType, GameMode, GameMods, OsuError, RankStatus, Scope},
+    Osu, OsuBuilder,
+};
+use time::{Date, Duration as TimeDuration, Month, OffsetDateTime};
+
+/// A request that was received by the [`MockServer`]
+#[derive(Clone, Debug)]
+struct Received {
+    method: Method,
+    /// Path and query
+    uri: String,
+    headers: HeaderMap,
+    body: String,
+}
+
+impl Received {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Local server that records all requests and responds through a handler
+struct MockServer {
+    url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+    /// Path prefix whose responses are delayed by the given duration
+    delay: Arc<Mutex<Option<(String, Duration)>>>,
+}
+
+impl MockServer {
+    fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Received) -> Response<Body> + Send + Sync + 'static,
+    {
+        let handler = Arc::new(handler);
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_clone = Arc::clone(&received);
+        let delay = Arc::new(Mutex::new(None::<(String, Duration)>));
+        let delay_clone = Arc::clone(&delay);
+
+        let make_service = make_service_fn(move |_| {
+            let handler = Arc::clone(&handler);
+            let received = Arc::clone(&received_clone);
+            let delay = Arc::clone(&delay_clone);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                    let handler = Arc::clone(&handler);
+                    let received = Arc::clone(&received);
+                    let delay = Arc::clone(&delay);
+
+                    async move {
+                        let (parts, body) = req.into_parts();
+                        let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+
+                        let req = Received {
+                            method: parts.method,
+                            uri: parts.uri.to_string(),
+                            headers: parts.headers,
+                            body: String::from_utf8_lossy(&body).into_owned(),
+                        };
+
+                        let resp = handler(&req);
+
+                        let delay = match &*delay.lock().unwrap() {
+                            Some((prefix, delay)) if req.uri.starts_with(prefix) => Some(*delay),
+                            _ => None,
+                        };
+
+                        received.lock().unwrap().push(req);
+
+                        if let Some(delay) = delay {
+                            tokio::time::sleep(delay).await;
+                        }
+
+                        Ok::<_, Infallible>(resp)
+                    }
+                }))
+            }
+        });
+
+        let addr = SocketAddr::from(([127, 0, 0, 1], 0));
+        let server = Server::bind(&addr).serve(make_service);
+        let url = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+
+        Self {
+            url,
+            received,
+            delay,
+        }
+    }
+
+    /// Delay responses to requests whose path starts with the given prefix
+    fn delay(&self, prefix: &str, delay: Duration) {
+        *self.delay.lock().unwrap() = Some((prefix.to_owned(), delay));
+    }
+
+    fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+
+    /// Amount of received requests whose path starts with the given prefix
+    fn count(&self, prefix: &str) -> usize {
+        self.received()
+            .iter()
+            .filter(|req| req.uri.starts_with(prefix))
+            .count()
+    }
+}
+
+fn json(status: StatusCode, body: impl Into<String>) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(body.into()))
+        .unwrap()
+}
+
+fn token(access: &str, refresh: Option<&str>) -> Response<Body> {
+    let refresh = refresh.map_or_else(|| "null".to_owned(), |refresh| format!(r#""{refresh}""#));
+
+    let body = format!(
+        r#"{{"access_token":"{access}","expires_in":86400,"refresh_token":{refresh},"token_type":"Bearer"}}"#
+    );
+
+    json(StatusCode::OK, body)
+}
+
+fn client_builder(server: &MockServer) -> OsuBuilder {
+    Osu::builder()
+        .client_id(1)
+        .client_secret("secret")
+        .base_url(&server.url)
+}
+
+#[tokio::test]
+async fn base_url_trims_trailing_slashes() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        "/api/v2/spotlights" => json(StatusCode::OK, r#"{"spotlights":[]}"#),
+        _ => json(StatusCode::NOT_FOUND, "{}"),
+    });
+
+    let osu = Osu::builder()
+        .client_id(1)
+        .client_secret("secret")
+        .base_url(format!("{}//", server.url))
+        .build()
+        .await
+        .unwrap();
+
+    let spotlights = osu.spotlights().await.unwrap();
+    assert!(spotlights.is_empty());
+
+    let received = server.received();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].method, Method::POST);
+    assert_eq!(received[0].uri, "/oauth/token");
+    assert_eq!(received[1].uri, "/api/v2/spotlights");
+    assert_eq!(received[1].header("authorization"), Some("Bearer access"));
+    assert_eq!(server.count("/api/v2/"), 1);
+}
+
+/// Token endpoint of a user-authorized client whose refresh tokens can only be used once
+fn user_token(req: &Received) -> Response<Body> {
+    if req.body.contains(r#""grant_type":"authorization_code""#) {
+        token("first", Some("refresh1"))
+    } else if req.body.contains(r#""refresh_token":"refresh1""#) {
+        token("second", Some("refresh2"))
+    } else {
+        json(StatusCode::BAD_REQUEST, r#"{"error":"invalid_grant"}"#)
+    }
+}
+
+#[tokio::test]
+async fn refresh_token_on_401() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => user_token(req),
+        "/api/v2/spotlights" if req.header("authorization") == Some("Bearer second") => {
+            json(StatusCode::OK, r#"{"spotlights":[]}"#)
+        }
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let osu = client_builder(&server)
+        .with_authorization("code", "http://localhost")
+        .build()
+        .await
+        .unwrap();
+
+    assert!(osu.spotlights().await.unwrap().is_empty());
+    assert_eq!(server.count("/oauth/token"), 2);
+    assert_eq!(server.count("/api/v2/spotlights"), 2);
+
+    let token = osu.token().await;
+    assert_eq!(token.access_token.as_deref(), Some("second"));
+    assert_eq!(token.refresh_token.as_deref(), Some("refresh2"));
+}
+
+#[tokio::test]
+async fn manual_token_refresh() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => user_token(req),
+        _ => json(StatusCode::NOT_FOUND, "{}"),
+    });
+
+    let osu = client_builder(&server)
+        .with_authorization("code", "http://localhost")
+        .build()
+        .await
+        .unwrap();
+
+    let expires_at = osu.token_expires_at().await.unwrap();
+    let in_a_day = OffsetDateTime::now_utc() + TimeDuration::days(1);
+    assert!((in_a_day - expires_at).abs() < TimeDuration::minutes(1));
+
+    osu.refresh_token().await.unwrap();
+    assert_eq!(server.count("/oauth/token"), 2);
+
+    let token = osu.token().await;
+    assert_eq!(token.access_token.as_deref(), Some("second"));
+    assert_eq!(token.refresh_token.as_deref(), Some("refresh2"));
+    assert!(osu.token_expires_at().await.unwrap() >= expires_at);
+
+    // The server only accepts `refresh1` so refreshing again fails
+    let err = osu.refresh_token().await.unwrap_err();
+    assert!(matches!(err, OsuError::UpdateToken { .. }), "{:?}", err);
+    assert_eq!(osu.token().await.access_token.as_deref(), Some("second"));
+}
+
+#[tokio::test]
+async fn on_token_refresh_hook() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => user_token(req),
+        "/api/v2/spotlights" if req.header("authorization") == Some("Bearer second") => {
+            json(StatusCode::OK, r#"{"spotlights":[]}"#)
+        }
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let refreshed = Arc::new(Mutex::new(Vec::new()));
+    let refreshed_clone = Arc::clone(&refreshed);
+
+    let osu = client_builder(&server)
+        .with_authorization("code", "http://localhost")
+        .on_token_refresh(move |token, expires_at| {
+            refreshed_clone
+                .lock()
+                .unwrap()
+                .push((token.clone(), expires_at));
+        })
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(refreshed.lock().unwrap().len(), 1);
+
+    // Refresh due to a 401 response
+    osu.spotlights().await.unwrap();
+
+    let expected_expiry = osu.token_expires_at().await;
+    let refreshed = refreshed.lock().unwrap();
+    assert_eq!(refreshed.len(), 2);
+
+    let (first, _) = &refreshed[0];
+    assert_eq!(first.access_token.as_deref(), Some("first"));
+    assert_eq!(first.refresh_token.as_deref(), Some("refresh1"));
+
+    let (second, expires_at) = &refreshed[1];
+    assert_eq!(second.access_token.as_deref(), Some("second"));
+    assert_eq!(second.refresh_token.as_deref(), Some("refresh2"));
+    assert_eq!(Some(*expires_at), expected_expiry);
+}
+
+#[tokio::test]
+async fn refresh_token_once_for_concurrent_401s() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => user_token(req),
+        "/api/v2/spotlights" if req.header("authorization") == Some("Bearer second") => {
+            json(StatusCode::OK, r#"{"spotlights":[]}"#)
+        }
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let osu = client_builder(&server)
+        .with_authorization("code", "http://localhost")
+        .build()
+        .await
+        .unwrap();
+
+    let (a, b, c) = tokio::join!(osu.spotlights(), osu.spotlights(), osu.spotlights());
+
+    for result in [a, b, c] {
+        assert!(result.unwrap().is_empty());
+    }
+
+    // One initial token and a single refresh; reusing `refresh1` would fail
+    assert_eq!(server.count("/oauth/token"), 2);
+    assert_eq!(osu.token().await.refresh_token.as_deref(), Some("refresh2"));
+}
+
+#[tokio::test]
+async fn no_refresh_for_client_credentials() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    assert!(osu.spotlights().await.is_err());
+    assert_eq!(server.count("/oauth/token"), 1);
+    assert_eq!(server.count("/api/v2/spotlights"), 1);
+}
+
+/// Beatmap routes respond with an `ETag` or with `304 Not Modified` if it was sent back
+fn etag_beatmaps(req: &Received) -> Response<Body> {
+    if req.uri == "/oauth/token" {
+        return token("access", None);
+    }
+
+    if req.header("if-none-match") == Some(r#""v1""#) {
+        return Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+            .unwrap();
+    }
+
+    let body = if req.uri.starts_with("/api/v2/beatmaps/1/scores") {
+        r#"{"scores":[]}"#
+    } else {
+        r#"{"beatmaps":[]}"#
+    };
+
+    let mut resp = json(StatusCode::OK, body);
+    resp.headers_mut()
+        .insert("etag", r#""v1""#.parse().unwrap());
+
+    resp
+}
+
+#[tokio::test]
+async fn etag_uses_cached_body() {
+    let server = MockServer::start(etag_beatmaps);
+
+    let osu = client_builder(&server)
+        .cache_etags(true)
+        .build()
+        .await
+        .unwrap();
+
+    assert!(osu.beatmaps([1]).await.unwrap().is_empty());
+    assert!(osu.beatmaps([1]).await.unwrap().is_empty());
+
+    let received = server.received();
+    assert_eq!(received.len(), 3);
+    assert_eq!(received[1].header("if-none-match"), None);
+    assert_eq!(received[2].header("if-none-match"), Some(r#""v1""#));
+}
+
+#[tokio::test]
+async fn etag_only_for_metadata() {
+    let server = MockServer::start(etag_beatmaps);
+
+    let osu = client_builder(&server)
+        .cache_etags(true)
+        .build()
+        .await
+        .unwrap();
+
+    osu.beatmap_scores(1).await.unwrap();
+    osu.beatmap_scores(1).await.unwrap();
+
+    assert!(server
+        .received()
+        .iter()
+        .all(|req| req.header("if-none-match").is_none()));
+}
+
+#[tokio::test]
+async fn etag_retry_after_clear_cache() {
+    let osu_slot = Arc::new(OnceCell::<Arc<Osu>>::new());
+    let osu_clone = Arc::clone(&osu_slot);
+
+    let server = MockServer::start(move |req| {
+        // Clear the cache while the conditional request is in flight
+        if req.header("if-none-match").is_some() {
+            if let Some(osu) = osu_clone.get() {
+                osu.clear_cache();
+            }
+        }
+
+        etag_beatmaps(req)
+    });
+
+    let osu = client_builder(&server)
+        .cache_etags(true)
+        .build()
+        .await
+        .unwrap();
+
+    let osu = Arc::clone(osu_slot.get_or_init(|| Arc::new(osu)));
+
+    assert!(osu.beatmaps([1]).await.unwrap().is_empty());
+    assert!(osu.beatmaps([1]).await.unwrap().is_empty());
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+    assert_eq!(received[2].header("if-none-match"), Some(r#""v1""#));
+    assert_eq!(received[3].header("if-none-match"), None);
+}
+
+#[tokio::test]
+async fn ping_checks_token() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        "/api/v2/spotlights" if req.header("authorization") == Some("Bearer access") => {
+            json(StatusCode::OK, r#"{"spotlights":[]}"#)
+        }
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    osu.ping().await.unwrap();
+
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("revoked", None),
+        "/api/v2/seasonal-backgrounds" => json(StatusCode::OK, r#"{"backgrounds":[]}"#),
+        _ => json(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    assert!(osu.ping().await.is_err());
+    assert_eq!(server.count("/api/v2/spotlights"), 1);
+}
+
+#[tokio::test]
+async fn friends_without_scope() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => user_token(req),
+        _ => json(StatusCode::FORBIDDEN, r#"{"authentication":"basic"}"#),
+    });
+
+    let osu = client_builder(&server)
+        .with_authorization("code", "http://localhost")
+        .scopes(&[Scope::Identify, Scope::FriendsRead])
+        .build()
+        .await
+        .unwrap();
+
+    let err = osu.friends().await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        OsuError::MissingScope {
+            scope: Scope::FriendsRead
+        }
+    ));
+}
+
+#[tokio::test]
+#[cfg(not(feature = "rkyv"))]
+async fn rankings_get_next_with_cursor_string() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        "/api/v2/rankings/osu/performance" => json(
+            StatusCode::OK,
+            r#"{"cursor":null,"cursor_string":"eyJwYWdlIjoyfQ","ranking":[],"total":10000}"#,
+        ),
+        _ => json(
+            StatusCode::OK,
+            r#"{"cursor":null,"ranking":[],"total":10000}"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let rankings = osu.performance_rankings(GameMode::Osu).await.unwrap();
+    let next = rankings.get_next(&osu).await.unwrap().unwrap();
+    assert!(next.get_next(&osu).await.is_none());
+
+    let received = server.received();
+    assert_eq!(
+        received[2].uri,
+        "/api/v2/rankings/osu/performance?cursor_string=eyJwYWdlIjoyfQ"
+    );
+}
+
+#[tokio::test]
+async fn mania_variant_for_other_mode() {
+    use rosu_v2::prelude::{GameMode, ManiaVariant};
+
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            r#"{"cursor":null,"ranking":[],"total":10000}"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let err = osu
+        .performance_rankings(GameMode::Osu)
+        .variant(ManiaVariant::FourKeys)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        OsuError::InvalidParameter {
+            param: "variant",
+            ..
+        }
+    ));
+    assert_eq!(server.count("/api/v2/"), 0);
+
+    osu.performance_rankings(GameMode::Mania)
+        .variant(ManiaVariant::FourKeys)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.received()[1].uri,
+        "/api/v2/rankings/mania/performance?variant=4k"
+    );
+}
+
+#[tokio::test]
+async fn news_year_out_of_range() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::NOT_FOUND, "{}"),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    for year in [2012, 9999] {
+        let err = osu.news().year(year).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            OsuError::InvalidParameter { param: "year", .. }
+        ));
+    }
+
+    assert_eq!(server.count("/api/v2/"), 0);
+}
+
+#[tokio::test]
+async fn news_post_encodes_slug() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::NOT_FOUND, "{}"),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let _ = osu.news_post("../me?a=b#c").await;
+
+    assert_eq!(server.received()[1].uri, "/api/v2/news/..%2Fme%3Fa%3Db%23c");
+}
+
+#[tokio::test]
+#[cfg(not(feature = "rkyv"))]
+async fn rankings_get_next_keeps_variant() {
+    use rosu_v2::prelude::{GameMode, ManiaVariant};
+
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        "/api/v2/rankings/mania/performance?variant=7k" => json(
+            StatusCode::OK,
+            r#"{"cursor":{"page":2},"ranking":[],"total":10000}"#,
+        ),
+        _ => json(
+            StatusCode::OK,
+            r#"{"cursor":null,"ranking":[],"total":10000}"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let rankings = osu
+        .performance_rankings(GameMode::Mania)
+        .variant(ManiaVariant::SevenKeys)
+        .await
+        .unwrap();
+
+    assert_eq!(rankings.next_page, Some(2));
+
+    rankings.get_next(&osu).await.unwrap().unwrap();
+
+    assert_eq!(
+        server.received()[2].uri,
+        "/api/v2/rankings/mania/performance?variant=7k&cursor[page]=2"
+    );
+}
+
+#[tokio::test]
+async fn beatmap_user_score_not_found() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::NOT_FOUND, r#"{"error":null}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    let score = osu.beatmap_user_score(123, 456).await.unwrap();
+
+    assert!(score.is_none());
+    assert_eq!(
+        server.received()[1].uri,
+        "/api/v2/beatmaps/123/scores/users/456"
+    );
+}
+
+#[tokio::test]
+#[cfg(feature = "cache")]
+async fn beatmap_user_score_unknown_username() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::NOT_FOUND, r#"{"error":null}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    let score = osu.beatmap_user_score(123, "unknown").await.unwrap();
+
+    assert!(score.is_none());
+    assert_eq!(server.count("/api/v2/beatmaps"), 0);
+}
+
+fn user_compact(user_id: u32) -> String {
+    format!(
+        r#"{{
+            "avatar_url": "",
+            "country_code": "BE",
+            "default_group": "default",
+            "id": {user_id},
+            "is_active": true,
+            "is_bot": false,
+            "is_deleted": false,
+            "is_online": false,
+            "is_supporter": false,
+            "pm_friends_only": false,
+            "username": "user{user_id}"
+        }}"#,
+        user_id = user_id
+    )
+}
+
+#[cfg(not(feature = "rkyv"))]
+fn ranking_user(user_id: u32) -> String {
+    format!(
+        r#"{{
+            "grade_counts": {{ "ss": 0, "ssh": 0, "s": 0, "sh": 0, "a": 0 }},
+            "hit_accuracy": 99.0,
+            "is_ranked": true,
+            "level": {{ "current": 100, "progress": 0 }},
+            "play_count": 1000,
+            "play_time": 3600,
+            "pp": 10000.0,
+            "ranked_score": 1000000,
+            "total_hits": 10000,
+            "total_score": 2000000,
+            "user": {}
+        }}"#,
+        user_compact(user_id)
+    )
+}
+
+#[tokio::test]
+#[cfg(not(feature = "rkyv"))]
+async fn rankings_limit_only_first_page() {
+    use rosu_v2::prelude::GameMode;
+
+    let server = MockServer::start(|req| {
+        let page: Vec<_> = (1..=3).map(ranking_user).collect();
+
+        let cursor = match req.uri.as_str() {
+            "/oauth/token" => return token("access", None),
+            "/api/v2/rankings/osu/score" => r#"{"page":2}"#,
+            _ => "null",
+        };
+
+        let body = format!(
+            r#"{{"cursor":{},"ranking":[{}],"total":10000}}"#,
+            cursor,
+            page.join(",")
+        );
+
+        json(StatusCode::OK, body)
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let rankings = osu.score_rankings(GameMode::Osu).limit(2).await.unwrap();
+
+    assert_eq!(rankings.ranking.len(), 2);
+    assert_eq!(rankings.next_page, Some(2));
+
+    let next = rankings.get_next(&osu).await.unwrap().unwrap();
+
+    assert_eq!(next.ranking.len(), 3);
+    assert_eq!(
+        server.received()[2].uri,
+        "/api/v2/rankings/osu/score?cursor[page]=2"
+    );
+}
+
+#[tokio::test]
+async fn kudosu_rankings() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        "/api/v2/rankings/kudosu?page=2" => {
+            let ranking: Vec<_> = (1..=2)
+                .map(|user_id| {
+                    let kudosu = format!(
+                        r#"{{"kudosu":{{"available":{},"total":{}}},"#,
+                        user_id,
+                        1000 - user_id
+                    );
+
+                    user_compact(user_id).replacen('{', &kudosu, 1)
+                })
+                .collect();
+
+            json(
+                StatusCode::OK,
+                format!(r#"{{"ranking":[{}]}}"#, ranking.join(",")),
+            )
+        }
+        _ => json(StatusCode::NOT_FOUND, "{}"),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    let users = osu.kudosu_rankings().page(2).await.unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1].user_id, 2);
+
+    let kudosu = users[1].kudosu.unwrap();
+    assert_eq!(kudosu.available, 2);
+    assert_eq!(kudosu.total, 998);
+}
+
+#[tokio::test]
+#[cfg(not(feature = "rkyv"))]
+async fn user_at_rank() {
+    let server = MockServer::start(|req| {
+        let (ids, total) = match req.uri.as_str() {
+            "/oauth/token" => return token("access", None),
+            "/api/v2/rankings/osu/performance?cursor[page]=3" => (101..=150, 210),
+            "/api/v2/rankings/osu/performance?country=be&cursor[page]=5" => (201..=210, 210),
+            _ => return json(StatusCode::NOT_FOUND, "{}"),
+        };
+
+        let page: Vec<_> = ids.map(ranking_user).collect();
+
+        let body = format!(
+            r#"{{"cursor":null,"ranking":[{}],"total":{}}}"#,
+            page.join(","),
+            total
+        );
+
+        json(StatusCode::OK, body)
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let user = osu.user_at_rank(GameMode::Osu, 123).await.unwrap();
+    assert_eq!(user.user_id, 123);
+
+    let err = osu.user_at_rank(GameMode::Osu, 0).await.unwrap_err();
+    assert!(matches!(
+        err,
+        OsuError::InvalidParameter { param: "rank", .. }
+    ));
+
+    let err = osu.user_at_rank(GameMode::Osu, 10_001).await.unwrap_err();
+    assert!(matches!(
+        err,
+        OsuError::InvalidParameter { param: "rank", .. }
+    ));
+    assert_eq!(server.count("/api/v2/rankings"), 1);
+
+    let err = osu
+        .user_at_rank(GameMode::Osu, 215)
+        .country("be")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        OsuError::InvalidParameter { param: "rank", .. }
+    ));
+    assert_eq!(server.count("/api/v2/rankings"), 2);
+}
+
+#[tokio::test]
+async fn seasonal_backgrounds() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            format!(
+                r#"{{
+                    "ends_at": "2030-01-01T00:00:00+00:00",
+                    "backgrounds": [{{ "url": "https://assets.ppy.sh/1.jpg", "user": {} }}]
+                }}"#,
+                user_compact(2)
+            ),
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    let backgrounds = osu.seasonal_backgrounds().await.unwrap();
+
+    assert_eq!(server.received()[1].uri, "/api/v2/seasonal-backgrounds");
+    assert_eq!(backgrounds.backgrounds.len(), 1);
+    assert_eq!(backgrounds.backgrounds[0].artist.user_id, 2);
+}
+
+#[tokio::test]
+async fn changelog_listing() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            r#"{
+                "builds": [],
+                "search": { "from": "2022.101.0", "limit": 21, "max_id": 6000, "stream": "lazer", "to": null },
+                "streams": []
+            }"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let listing = osu
+        .changelog()
+        .stream("lazer")
+        .from("2022.101.0")
+        .max_id(6000)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.received()[1].uri,
+        "/api/v2/changelog?stream=lazer&from=2022.101.0&max_id=6000"
+    );
+    assert_eq!(listing.search.max_id, Some(6000));
+}
+
+#[tokio::test]
+async fn changelog_build() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            r#"{
+                "created_at": "2022-01-01T12:00:00+00:00",
+                "display_version": "2022.101.0",
+                "id": 6000,
+                "users": 1234,
+                "version": "2022.101.0",
+                "changelog_entries": [{
+                    "category": "Gameplay",
+                    "major": false,
+                    "title": "Fix something",
+                    "type": "fix",
+                    "github_user": { "display_name": "peppy", "user_id": 2 }
+                }]
+            }"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let build = osu.changelog_build("lazer", "2022.101.0").await.unwrap();
+    assert_eq!(build.changelog_entries[0].entry_type, "fix");
+
+    osu.changelog_build_by_id(6000).await.unwrap();
+
+    let _ = osu.changelog_build("web/dev", "2022.101.0?a=b").await;
+
+    let received = server.received();
+    assert_eq!(received[1].uri, "/api/v2/changelog/lazer/2022.101.0");
+    assert_eq!(received[2].uri, "/api/v2/changelog/6000?key=id");
+    assert_eq!(
+        received[3].uri,
+        "/api/v2/changelog/web%2Fdev/2022.101.0%3Fa%3Db"
+    );
+}
+
+fn map_json(map_id: u32, mapset_id: u32, stars: f32) -> String {
+    format!(
+        r#"{{
+            "accuracy": 9.0,
+            "ar": 9.3,
+            "beatmapset_id": {mapset_id},
+            "bpm": 180.0,
+            "convert": false,
+            "count_circles": 400,
+            "count_sliders": 200,
+            "count_spinners": 1,
+            "cs": 4.0,
+            "difficulty_rating": {stars},
+            "drain": 5.0,
+            "hit_length": 180,
+            "id": {map_id},
+            "is_scoreable": true,
+            "last_updated": "2021-01-01T00:00:00+00:00",
+            "max_combo": 1000,
+            "mode": "osu",
+            "passcount": 100,
+            "playcount": 1000,
+            "ranked": 1,
+            "status": "ranked",
+            "total_length": 200,
+            "url": "https://osu.ppy.sh/beatmaps/{map_id}",
+            "user_id": 2,
+            "version": "Insane"
+        }}"#,
+        map_id = map_id,
+        mapset_id = mapset_id,
+        stars = stars
+    )
+}
+
+fn mapset_json(mapset_id: u32, maps: &[String]) -> String {
+    format!(
+        r#"{{
+            "artist": "Artist",
+            "availability": {{ "download_disabled": false, "more_information": null }},
+            "beatmaps": [{maps}],
+            "bpm": 180.0,
+            "can_be_hyped": false,
+            "covers": {{
+                "cover": "", "cover@2x": "", "card": "", "card@2x": "",
+                "list": "", "list@2x": "", "slimcover": "", "slimcover@2x": ""
+            }},
+            "creator": "mapper",
+            "description": {{ "description": "<p>description</p>" }},
+            "discussion_enabled": true,
+            "discussion_locked": false,
+            "favourite_count": 10,
+            "genre": {{ "id": 2, "name": "Video Game" }},
+            "id": {mapset_id},
+            "is_scoreable": true,
+            "language": {{ "id": 3, "name": "Japanese" }},
+            "last_updated": "2021-01-01T00:00:00+00:00",
+            "nominations_summary": {{ "current": 2, "required": 2 }},
+            "nsfw": false,
+            "play_count": 1000,
+            "preview_url": "",
+            "ratings": [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            "source": "",
+            "status": "ranked",
+            "storyboard": false,
+            "tags": "",
+            "title": "Title",
+            "user_id": 2,
+            "video": false
+        }}"#,
+        maps = maps.join(","),
+        mapset_id = mapset_id
+    )
+}
+
+#[tokio::test]
+async fn beatmapset_with_maps() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            mapset_json(1, &[map_json(10, 1, 4.5), map_json(11, 1, 6.25)]),
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+    let mapset = osu.beatmapset(1).await.unwrap();
+
+    assert_eq!(server.received()[1].uri, "/api/v2/beatmapsets/1");
+    assert_eq!(mapset.description.as_deref(), Some("<p>description</p>"));
+    assert_eq!(mapset.ratings.as_ref().map(Vec::len), Some(11));
+    assert!(mapset.genre.is_some());
+    assert!(mapset.language.is_some());
+
+    let stars: Vec<_> = mapset.maps.unwrap().iter().map(|map| map.stars).collect();
+    assert_eq!(stars, [4.5, 6.25]);
+}
+
+#[tokio::test]
+async fn beatmap_lookup() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::OK, map_json(10, 1, 4.5)),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let map = osu.beatmap().map_id(10).await.unwrap();
+    assert_eq!(server.received()[1].uri, "/api/v2/beatmaps/lookup?id=10");
+    assert_eq!(map.status, RankStatus::Ranked);
+    assert_eq!(map.max_combo, Some(1000));
+    assert_eq!(map.count_circles, 400);
+
+    osu.beatmap()
+        .checksum("a708a5b90349e98b399f2a1c9fce5422")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.received()[2].uri,
+        "/api/v2/beatmaps/lookup?checksum=a708a5b90349e98b399f2a1c9fce5422"
+    );
+
+    osu.beatmap()
+        .filename("A & B - C+D (mapper) [#1].osu")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.received()[3].uri,
+        "/api/v2/beatmaps/lookup?filename=A+%26+B+-+C%2BD+%28mapper%29+%5B%231%5D.osu"
+    );
+}
+
+#[tokio::test]
+async fn user_scores_include_fails() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::OK, "[]"),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    for scores in [osu.user_scores(2).best(), osu.user_scores(2).pinned()] {
+        let err = scores.include_fails(true).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            OsuError::InvalidParameter {
+                param: "include_fails",
+                ..
+            }
+        ));
+    }
+
+    assert_eq!(server.count("/api/v2/"), 0);
+
+    osu.user_scores(2)
+        .recent()
+        .include_fails(true)
+        .await
+        .unwrap();
+    osu.user_scores(2).recent().await.unwrap();
+
+    let received = server.received();
+    assert_eq!(
+        received[1].uri,
+        "/api/v2/users/2/scores/recent?include_fails=1"
+    );
+    assert_eq!(received[2].uri, "/api/v2/users/2/scores/recent");
+}
+
+#[tokio::test]
+async fn users_map_chunks() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        uri => {
+            // Respond with all requested users except restricted ones, i.e. id 7
+            let users: Vec<_> = uri
+                .split("ids[]=")
+                .skip(1)
+                .map(|id| id.trim_end_matches('&').parse().unwrap())
+                .filter(|&id| id != 7)
+                .map(user_compact)
+                .collect();
+
+            json(
+                StatusCode::OK,
+                format!(r#"{{"users":[{}]}}"#, users.join(",")),
+            )
+        }
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let mut ids: Vec<u32> = (1..=120).rev().collect();
+    ids.extend([3, 3, 120]);
+
+    let users = osu.users_map(&ids).await.unwrap();
+
+    assert_eq!(users.len(), 119);
+    assert!(!users.contains_key(&7));
+    assert_eq!(users[&120].user_id, 120);
+    assert_eq!(server.count("/api/v2/users?"), 3);
+
+    assert!(osu.users_map(&[]).await.unwrap().is_empty());
+    assert_eq!(server.count("/api/v2/users?"), 3);
+}
+
+#[tokio::test]
+async fn request_timeout_override() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::OK, r#"{"cursor":null,"ranking":[],"total":0}"#),
+    });
+
+    server.delay("/api/v2/rankings", Duration::from_millis(200));
+
+    let osu = client_builder(&server)
+        .timeout(Duration::from_millis(50))
+        .retries(1)
+        .build()
+        .await
+        .unwrap();
+
+    let err = osu.performance_rankings(GameMode::Osu).await.unwrap_err();
+    assert!(matches!(err, OsuError::RequestTimeout), "{:?}", err);
+    assert_eq!(server.count("/api/v2/rankings"), 2);
+
+    let rankings = osu
+        .performance_rankings(GameMode::Osu)
+        .timeout(Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert!(rankings.ranking.is_empty());
+    assert_eq!(server.count("/api/v2/rankings"), 3);
+}
+
+#[tokio::test]
+async fn beatmapset_events_filters() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(
+            StatusCode::OK,
+            r#"{"events":[],"reviewsConfig":{"max_blocks":10},"users":[]}"#,
+        ),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let events = osu
+        .beatmapset_events()
+        .user(2)
+        .types(&[
+            BeatmapsetEventType::Nominate,
+            BeatmapsetEventType::NominationReset,
+            BeatmapsetEventType::OwnerChange,
+        ])
+        .min_date(Date::from_calendar_date(2022, Month::January, 1).unwrap())
+        .max_date(Date::from_calendar_date(2022, Month::December, 31).unwrap())
+        .page(2)
+        .limit(50)
+        .await
+        .unwrap();
+
+    assert!(events.events.is_empty());
+    assert_eq!(
+        server.received()[1].uri,
+        "/api/v2/beatmapsets/events?user=2&types[]=nominate&types[]=nomination_reset\
+        &types[]=beatmap_owner_change&min_date=2022-01-01&max_date=2022-12-31&page=2&limit=50"
+    );
+
+    osu.beatmapset_events().await.unwrap();
+    assert_eq!(server.received()[2].uri, "/api/v2/beatmapsets/events");
+}
+
+#[tokio::test]
+async fn beatmap_scores_mods() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        _ => json(StatusCode::OK, r#"{"scores":[]}"#),
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    osu.beatmap_scores(10)
+        .mods(GameMods::Hidden | GameMods::HardRock)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.received()[1].uri,
+        "/api/v2/beatmaps/10/scores?mods[]=HD&mods[]=HR"
+    );
+
+    osu.beatmap_scores(10).mods(GameMods::NoMod).await.unwrap();
+    assert_eq!(
+        server.received()[2].uri,
+        "/api/v2/beatmaps/10/scores?mods[]=NM"
+    );
+}
+
+#[tokio::test]
+async fn beatmaps_chunks_in_order() {
+    let server = MockServer::start(|req| match req.uri.as_str() {
+        "/oauth/token" => token("access", None),
+        uri => {
+            // Respond in reverse order and without the deleted map 7
+            let mut maps: Vec<_> = uri
+                .split("ids[]=")
+                .skip(1)
+                .map(|id| id.trim_end_matches('&').parse().unwrap())
+                .filter(|&id| id != 7)
+                .map(|id| map_json(id, 1, 5.0))
+                .collect();
+
+            maps.reverse();
+
+            json(
+                StatusCode::OK,
+                format!(r#"{{"beatmaps":[{}]}}"#, maps.join(",")),
+            )
+        }
+    });
+
+    let osu = client_builder(&server).build().await.unwrap();
+
+    let mut ids: Vec<u32> = (1..=60).rev().collect();
+    ids.extend([3, 60]);
+
+    let maps = osu.beatmaps(ids).await.unwrap();
+    let map_ids: Vec<_> = maps.iter().map(|map| map.map_id).collect();
+
+    let expected: Vec<u32> = (1..=60).rev().filter(|&id| id != 7).collect();
+    assert_eq!(map_ids, expected);
+    assert_eq!(server.count("/api/v2/beatmaps?"), 2);
+}
@@ -134,7 +134,7 @@ async fn beatmaps() -> Result<()> {
 #[tokio::test]
 async fn beatmap_scores() -> Result<()> {
     let scores = OSU.get().await?.beatmap_scores(ADESSO_BALLA).await?;
-    println!("Received {} scores", scores.len());
+    println!("Received {} scores", scores.scores.len());
 
     Ok(())
 }
@@ -147,7 +147,8 @@ async fn beatmap_user_score() -> Result<()> {
         .await?
         .beatmap_user_score(ADESSO_BALLA, BADEWANNE3)
         .mods(GameMods::Hidden | GameMods::HardRock | GameMods::HalfTime)
-        .await?;
+        .await?
+        .ok_or_else(|| eyre::eyre!("missing score"))?;
 
     println!(
         "Received score, pos={} | mods={}",
@@ -238,6 +239,14 @@ async fn comments() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn events() -> Result<()> {
+    let events = OSU.get().await?.events().sort_descending().await?;
+    println!("Received {} events", events.events.len());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn chart_rankings() -> Result<()> {
     let rankings = OSU.get().await?.chart_rankings(GameMode::Osu).await?;
@@ -279,6 +288,15 @@ async fn forum_posts() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore = "requires OAuth to not throw an error"]
+async fn friends() -> Result<()> {
+    let friends = OSU.get().await?.friends().await?;
+    println!("Received {} friends", friends.len());
+
+    Ok(())
+}
+
 #[cfg(feature = "cache")]
 #[tokio::test]
 async fn recent_events() -> Result<()> {
@@ -384,7 +402,8 @@ async fn score() -> Result<()> {
     let score = OSU
         .get()
         .await?
-        .score(COOKIEZI_FREEDOM_DIVE, GameMode::Osu)
+        .score(COOKIEZI_FREEDOM_DIVE)
+        .mode(GameMode::Osu)
         .await?;
 
     println!(
@@ -408,6 +427,14 @@ async fn score_rankings() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn ping() -> Result<()> {
+    OSU.get().await?.ping().await?;
+    println!("Received pong");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn seasonal_backgrounds() -> Result<()> {
     let backgrounds = OSU.get().await?.seasonal_backgrounds().await?;
@@ -502,9 +529,7 @@ async fn user_scores() -> Result<()> {
 }
 
 #[tokio::test]
-#[ignore = "currently unavailable"]
 async fn users() -> Result<()> {
-    #[allow(deprecated)]
     let users = OSU.get().await?.users(&[BADEWANNE3, SYLAS]).await?;
     println!("Received {} users", users.len());
 
@@ -26,6 +26,16 @@ mod types {
         }
     }
 
+    /// `CountryRanking`'s `PartialEq` only compares the country code so compare all fields instead
+    pub(super) fn assert_country_ranking_eq(a: &CountryRanking, b: &CountryRanking) {
+        assert_eq!(a.active_users, b.active_users);
+        assert_eq!(a.country, b.country);
+        assert_eq!(a.country_code, b.country_code);
+        assert_eq!(a.playcount, b.playcount);
+        assert_eq!(a.pp, b.pp);
+        assert_eq!(a.ranked_score, b.ranked_score);
+    }
+
     pub(super) fn get_cursor() -> Cursor {
         let json = r#"{"cursor":{"a":123,"b":"henlo","c":true,"d":[1, 2, 3]}}"#;
 
@@ -42,6 +52,7 @@ mod types {
     pub(super) fn get_forum_posts() -> ForumPosts {
         ForumPosts {
             cursor: Some(get_cursor()),
+            cursor_string: None,
             posts: vec![ForumPost {
                 created_at: get_date(),
                 deleted_at: Some(get_date()),
@@ -494,6 +505,11 @@ mod types {
                 count_100: 3,
                 count_50: 4,
                 count_miss: 5,
+                large_tick_hit: 0,
+                large_tick_miss: 0,
+                small_tick_hit: 0,
+                small_tick_miss: 0,
+                slider_tail_hit: 0,
             },
             slot: 0,
             team: Team::Red,
@@ -501,6 +517,39 @@ mod types {
         }
     }
 
+    pub(super) fn get_multiplayer_score() -> MultiplayerScore {
+        MultiplayerScore {
+            accuracy: 98.76,
+            ended_at: get_date(),
+            grade: Grade::S,
+            map_id: 123,
+            max_combo: 1234,
+            mods: GameMods::Hidden | GameMods::HardRock,
+            passed: true,
+            playlist_id: 42,
+            position: Some(3),
+            pp: Some(123.45),
+            room_id: 69,
+            score: 12_345_678,
+            score_id: 987_654_321,
+            statistics: ScoreStatistics {
+                count_geki: 0,
+                count_300: 1000,
+                count_katu: 0,
+                count_100: 20,
+                count_50: 2,
+                count_miss: 1,
+                large_tick_hit: 0,
+                large_tick_miss: 0,
+                small_tick_hit: 0,
+                small_tick_miss: 0,
+                slider_tail_hit: 0,
+            },
+            user: Some(get_user_compact()),
+            user_id: 2,
+        }
+    }
+
     pub(super) fn get_score() -> Score {
         Score {
             accuracy: 98.76,
@@ -512,6 +561,7 @@ mod types {
             map: Some(get_map()),
             mapset: Some(get_mapset_compact()),
             mode: GameMode::Catch,
+            id: 123_456_789,
             mods: GameMods::Hidden | GameMods::DoubleTime,
             perfect: false,
             pp: Some(456.78),
@@ -527,6 +577,11 @@ mod types {
                 count_100: 300,
                 count_50: 200,
                 count_miss: 1,
+                large_tick_hit: 0,
+                large_tick_miss: 0,
+                small_tick_hit: 0,
+                small_tick_miss: 0,
+                slider_tail_hit: 0,
             },
             user: Some(get_user_compact()),
             user_id: 2,
@@ -534,6 +589,10 @@ mod types {
                 percentage: 1.0,
                 pp: 456.78,
             }),
+            build_id: None,
+            legacy_score_id: Some(123_456_789_000),
+            if_fc_pp: None,
+            max_pp: None,
         }
     }
 
@@ -554,7 +613,7 @@ mod types {
             name: "epic spotlight".to_owned(),
             participant_count: Some(3),
             spotlight_id: 2,
-            spotlight_type: "idk".to_owned(),
+            spotlight_type: SpotlightType::Other("idk".to_owned()),
             start_date: get_date(),
         }
     }
@@ -639,6 +698,7 @@ mod types {
             is_limited_bn: Some(true),
             is_moderator: Some(true),
             is_nat: Some(true),
+            is_restricted: Some(false),
             is_silenced: Some(true),
             loved_mapset_count: Some(3),
             mapping_follower_count: Some(5),
@@ -660,7 +720,12 @@ mod types {
             scores_best_count: Some(13),
             scores_first_count: Some(13),
             scores_recent_count: Some(13),
+            session_verified: Some(true),
             statistics: Some(get_user_stats()),
+            statistics_rulesets: Some(UserStatisticsRulesets {
+                taiko: Some(get_user_stats()),
+                ..Default::default()
+            }),
             support_level: Some(3),
             pending_mapset_count: Some(13),
             medals: Some(vec![MedalCompact {
@@ -730,6 +795,10 @@ mod types {
             is_moderator: Some(false),
             is_nat: Some(false),
             is_silenced: Some(false),
+            kudosu: Some(UserKudosu {
+                available: 2,
+                total: 5,
+            }),
             loved_mapset_count: Some(34),
             medals: Some(vec![MedalCompact {
                 achieved_at: get_date(),
@@ -754,8 +823,11 @@ mod types {
             scores_first_count: Some(34),
             scores_recent_count: Some(34),
             statistics: Some(get_user_stats()),
+            statistics_rulesets: None,
             support_level: Some(1),
             pending_mapset_count: Some(34),
+            #[cfg(feature = "raw-extra")]
+            extra: serde_json::Value::Object(Default::default()),
         }
     }
 
@@ -786,7 +858,7 @@ mod types {
             max_combo: 6543,
             playcount: 100_000,
             playtime: 10_000_000,
-            pp: 9876.54,
+            pp: Some(9876.54),
             ranked_score: 111_222_333_444,
             replays_watched: 123,
             total_hits: 123_456_789,
@@ -837,7 +909,7 @@ mod serde_tests {
 
     use super::types::*;
 
-    fn roundtrip<T>(val: &T)
+    fn roundtrip<T>(val: &T) -> T
     where
         T: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug,
     {
@@ -848,6 +920,8 @@ mod serde_tests {
             .unwrap_or_else(|e| panic!("Failed to deserialize: {}\n{serialized}", e));
 
         assert_eq!(val, &deserialized);
+
+        deserialized
     }
 
     #[test]
@@ -872,7 +946,8 @@ mod serde_tests {
 
     #[test]
     fn serde_country_ranking() {
-        roundtrip(&get_country_ranking());
+        let ranking = get_country_ranking();
+        assert_country_ranking_eq(&ranking, &roundtrip(&ranking));
     }
 
     #[test]
@@ -885,6 +960,11 @@ mod serde_tests {
         roundtrip(&get_match());
     }
 
+    #[test]
+    fn serde_multiplayer_score() {
+        roundtrip(&get_multiplayer_score());
+    }
+
     #[test]
     fn serde_score() {
         roundtrip(&get_score());
@@ -910,7 +990,7 @@ mod rkyv_tests {
 
     use super::types::*;
 
-    fn roundtrip<T>(val: &T)
+    fn roundtrip<T>(val: &T) -> T
     where
         T: PartialEq + std::fmt::Debug + Archive + Serialize<AllocSerializer<512>>,
         <T as Archive>::Archived: Deserialize<T, Infallible>,
@@ -923,6 +1003,8 @@ mod rkyv_tests {
                 .unwrap_or_else(|e| panic!("Failed to deserialize: {}", e));
 
         assert_eq!(val, &deserialized);
+
+        deserialized
     }
 
     #[test]
@@ -947,7 +1029,8 @@ mod rkyv_tests {
 
     #[test]
     fn serde_country_ranking() {
-        roundtrip(&get_country_ranking());
+        let ranking = get_country_ranking();
+        assert_country_ranking_eq(&ranking, &roundtrip(&ranking));
     }
 
     // TODO
@@ -961,6 +1044,11 @@ mod rkyv_tests {
         roundtrip(&get_match());
     }
 
+    #[test]
+    fn serde_multiplayer_score() {
+        roundtrip(&get_multiplayer_score());
+    }
+
     #[test]
     fn serde_score() {
         roundtrip(&get_score());